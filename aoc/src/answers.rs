@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+
+/// Known-correct answers for the real puzzle inputs, stored as TOML with one
+/// table per day:
+///
+/// ```toml
+/// [day14]
+/// part2 = "23610"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl Answers {
+    /// Reads the answers in `path`; a missing file holds no answers.
+    pub fn load(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_toml()).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut answers = Self::default();
+        for (key, parts) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a table like [day1], found [{}]", key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("[{}] is not a table", key))?;
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("unknown part {}.{}", key, name)),
+                };
+                let answer = answer
+                    .as_str()
+                    .ok_or_else(|| format!("{}.{} is not a string", key, name))?;
+                answers.set(day, part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Writes the days in numeric order, which TOML tables would not keep.
+    fn to_toml(&self) -> String {
+        let mut result = String::new();
+        let mut current_day = None;
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(*day) {
+                if current_day.is_some() {
+                    result.push('\n');
+                }
+                writeln!(result, "[day{}]", day).unwrap();
+                current_day = Some(*day);
+            }
+            let value = toml::Value::String(answer.clone());
+            writeln!(result, "part{} = {}", part, value).unwrap();
+        }
+        result
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answers::{Answers, Check};
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(14, 2, "23610");
+        answers.set(2, 1, "15");
+        answers.set(10, 2, "#..#\n.##.");
+        let text = answers.to_toml();
+        assert!(text.starts_with("[day2]\npart1 = \"15\"\n\n[day10]\n"));
+        assert_eq!(Answers::parse(&text), Ok(answers));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day14]\npart2 = \"23610\"\n").unwrap();
+        assert_eq!(answers.check(14, 2, "23610"), Check::Correct);
+        assert_eq!(
+            answers.check(14, 2, "93"),
+            Check::Wrong("23610".to_string())
+        );
+        assert_eq!(answers.check(14, 1, "24"), Check::Unknown);
+        assert!(Answers::parse("[monkey]\npart1 = \"1\"\n").is_err());
+        assert!(Answers::parse("[day1]\npart3 = \"1\"\n").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+/// How long parsing and each requested part took in a single run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    /// Turning the text into the day's typed input, which the parts then
+    /// use as is.
+    pub parse: Duration,
+    pub parts: Vec<(u32, Duration)>,
+}
+
+impl Timings {
+    /// Stage names and durations in the order they ran.
+    pub fn stages(&self) -> Vec<(String, Duration)> {
+        let mut stages = vec![("parse".to_string(), self.parse)];
+        stages.extend(
+            self.parts
+                .iter()
+                .map(|(part, time)| (format!("part{}", part), *time)),
+        );
+        stages
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|(_, time)| *time).sum::<Duration>()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        Self {
+            min: sorted[0],
+            median: percentile(&sorted, 50),
+            p95: percentile(&sorted, 95),
+        }
+    }
+}
+
+/// Nearest-rank percentile of already sorted, non-empty samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+/// Statistics of one stage of one day over all benchmark runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u32,
+    pub stage: String,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+/// Groups the timings of repeated runs of `day` by stage.
+pub fn bench_results(day: u32, runs: &[Timings]) -> Vec<BenchResult> {
+    let mut results: Vec<(String, Vec<Duration>)> = Vec::new();
+    for timings in runs {
+        for (idx, (stage, time)) in timings.stages().into_iter().enumerate() {
+            match results.get_mut(idx) {
+                Some((_, samples)) => samples.push(time),
+                None => results.push((stage, vec![time])),
+            }
+        }
+    }
+    results
+        .into_iter()
+        .map(|(stage, samples)| BenchResult {
+            day,
+            stage,
+            runs: samples.len(),
+            stats: Stats::new(&samples),
+        })
+        .collect()
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn timings_table(timings: &Timings) -> String {
+    let mut result = String::new();
+    for (stage, time) in timings.stages() {
+        writeln!(result, "{:<8}{:>12}", stage, format_duration(time)).unwrap();
+    }
+    writeln!(
+        result,
+        "{:<8}{:>12}",
+        "total",
+        format_duration(timings.total())
+    )
+    .unwrap();
+    result
+}
+
+pub fn bench_table(results: &[BenchResult]) -> String {
+    let mut result = format!(
+        "{:>4}  {:<8}{:>12}{:>12}{:>12}\n",
+        "day", "stage", "min", "median", "p95"
+    );
+    for r in results {
+        writeln!(
+            result,
+            "{:>4}  {:<8}{:>12}{:>12}{:>12}",
+            r.day,
+            r.stage,
+            format_duration(r.stats.min),
+            format_duration(r.stats.median),
+            format_duration(r.stats.p95)
+        )
+        .unwrap();
+    }
+    result
+}
+
+/// One tab-separated line per day and stage, times in nanoseconds.
+pub fn report_text(results: &[BenchResult]) -> String {
+    let mut result = "day\tstage\truns\tmin_ns\tmedian_ns\tp95_ns\n".to_string();
+    for r in results {
+        writeln!(
+            result,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            r.day,
+            r.stage,
+            r.runs,
+            r.stats.min.as_nanos(),
+            r.stats.median.as_nanos(),
+            r.stats.p95.as_nanos()
+        )
+        .unwrap();
+    }
+    result
+}
+
+pub fn report_json(results: &[BenchResult]) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"day\": {}, \"stage\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                r.day,
+                r.stage,
+                r.runs,
+                r.stats.min.as_nanos(),
+                r.stats.median.as_nanos(),
+                r.stats.p95.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::bench::{bench_results, format_duration, report_json, report_text, Stats, Timings};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(ms).collect();
+        assert_eq!(
+            Stats::new(&samples),
+            Stats {
+                min: ms(1),
+                median: ms(10),
+                p95: ms(19)
+            }
+        );
+        assert_eq!(Stats::new(&[ms(3)]).p95, ms(3));
+    }
+
+    #[test]
+    fn test_bench_results() {
+        let runs: Vec<Timings> = (1..=3)
+            .map(|n| Timings {
+                parse: ms(n),
+                parts: vec![(1, ms(10 * n)), (2, ms(100 * n))],
+            })
+            .collect();
+        let results = bench_results(6, &runs);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2].stage, "part2");
+        assert_eq!(results[2].stats.median, ms(200));
+        assert_eq!(
+            report_text(&results[..1]),
+            "day\tstage\truns\tmin_ns\tmedian_ns\tp95_ns\n6\tparse\t3\t1000000\t2000000\t3000000\n"
+        );
+        assert!(report_json(&results).contains("{\"day\": 6, \"stage\": \"part1\", \"runs\": 3"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+    }
+}
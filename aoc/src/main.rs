@@ -0,0 +1,476 @@
+use std::{
+    env, fs,
+    path::Path,
+    process::exit,
+    time::{Duration, Instant},
+};
+
+use answers::{Answers, Check};
+use bench::{bench_results, bench_table, report_json, report_text, timings_table, Timings};
+use utils::{inputs::InputCache, Input, ParseError, Solution};
+
+mod answers;
+mod bench;
+
+const YEAR: u32 = 2022;
+const LAST_DAY: u32 = 17;
+const USAGE: &str = "Usage: aoc run <day> [--part <1|2>] [--input <path|->] [--time] [--explain]
+       aoc verify [<day>...] [--record] [--answers <path>]
+       aoc bench [<day>...] [--runs <n>] [--output <path>]";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunArgs {
+    day: u32,
+    parts: Vec<u32>,
+    input: Option<String>,
+    time: bool,
+    explain: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct VerifyArgs {
+    days: Vec<u32>,
+    record: bool,
+    answers: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct BenchArgs {
+    days: Vec<u32>,
+    runs: usize,
+    output: String,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("verify") => parse_verify_args(args).map(Command::Verify),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err("No command given".to_string()),
+    }
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let day: u32 = args
+        .next()
+        .ok_or("No day given")?
+        .parse()
+        .map_err(|_| "Day must be a number")?;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut time = false;
+    let mut explain = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args
+                    .next()
+                    .ok_or("--part needs a value")?
+                    .parse()
+                    .map_err(|_| "Part must be a number")?;
+                if part != 1 && part != 2 {
+                    return Err(format!("Unknown part {}", part));
+                }
+                parts = vec![part];
+            }
+            "--input" => input = Some(args.next().ok_or("--input needs a value")?),
+            "--time" => time = true,
+            "--explain" => explain = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+    Ok(RunArgs {
+        day,
+        parts,
+        input,
+        time,
+        explain,
+    })
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut days = Vec::new();
+    let mut record = false;
+    let mut answers = "answers.toml".to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--record" => record = true,
+            "--answers" => answers = args.next().ok_or("--answers needs a value")?,
+            _ => days.push(
+                arg.parse()
+                    .map_err(|_| format!("Unknown argument '{}'", arg))?,
+            ),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=LAST_DAY).collect();
+    }
+    Ok(VerifyArgs {
+        days,
+        record,
+        answers,
+    })
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut days = Vec::new();
+    let mut runs = 10;
+    let mut output = "bench_output.txt".to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = args
+                    .next()
+                    .ok_or("--runs needs a value")?
+                    .parse()
+                    .map_err(|_| "Runs must be a number")?;
+                if runs == 0 {
+                    return Err("Runs must be at least 1".to_string());
+                }
+            }
+            "--output" => output = args.next().ok_or("--output needs a value")?,
+            _ => days.push(
+                arg.parse()
+                    .map_err(|_| format!("Unknown argument '{}'", arg))?,
+            ),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=LAST_DAY).collect();
+    }
+    Ok(BenchArgs { days, runs, output })
+}
+
+/// Answers of a single run, how they come about when asked for, and the
+/// time each stage took.
+#[derive(Debug)]
+struct Outcome {
+    answers: Vec<String>,
+    explanations: Vec<Option<String>>,
+    timings: Timings,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn run<S: Solution>(input: &str, parts: &[u32], explain: bool) -> Result<Outcome, ParseError> {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let params = S::Params::default();
+    let mut answers = Vec::new();
+    let mut explanations = Vec::new();
+    let mut timings = Timings {
+        parse: parse_time,
+        parts: Vec::new(),
+    };
+    for &part in parts {
+        let (answer, time) = timed(|| match part {
+            1 => S::part1(&parsed, &params).to_string(),
+            _ => S::part2(&parsed, &params).to_string(),
+        });
+        answers.push(answer);
+        timings.parts.push((part, time));
+        explanations.push(if explain {
+            S::explain(&parsed, &params, part)
+        } else {
+            None
+        });
+    }
+    Ok(Outcome {
+        answers,
+        explanations,
+        timings,
+    })
+}
+
+/// Reads the input given with `--input`, or else `day<N>/input.txt`, or else
+/// the input from the cache, which downloads it when needed.
+fn read_input(day: u32, input: Option<&str>) -> Result<String, String> {
+    let local = format!("day{}/input.txt", day);
+    let path = match input {
+        Some(path) => path,
+        None if Path::new(&local).exists() => &local,
+        None => {
+            let cache = InputCache::from_env();
+            return cache.get(YEAR, day).map_err(|e| {
+                format!(
+                    "Failed to get input {}: {}",
+                    cache.path(YEAR, day).display(),
+                    e
+                )
+            });
+        }
+    };
+    Input::path(path)
+        .read_to_string()
+        .map_err(|e| format!("Failed to read input {}: {}", path, e))
+}
+
+fn solve(
+    day: u32,
+    input: &str,
+    parts: &[u32],
+    explain: bool,
+) -> Option<Result<Outcome, ParseError>> {
+    Some(match day {
+        1 => run::<day1::Day1>(input, parts, explain),
+        2 => run::<day2::Day2>(input, parts, explain),
+        3 => run::<day3::Day3>(input, parts, explain),
+        4 => run::<day4::Day4>(input, parts, explain),
+        5 => run::<day5::Day5>(input, parts, explain),
+        6 => run::<day6::Day6>(input, parts, explain),
+        7 => run::<day7::Day7>(input, parts, explain),
+        8 => run::<day8::Day8>(input, parts, explain),
+        9 => run::<day9::Day9>(input, parts, explain),
+        10 => run::<day10::Day10>(input, parts, explain),
+        11 => run::<day11::Day11>(input, parts, explain),
+        12 => run::<day12::Day12>(input, parts, explain),
+        13 => run::<day13::Day13>(input, parts, explain),
+        14 => run::<day14::Day14>(input, parts, explain),
+        15 => run::<day15::Day15>(input, parts, explain),
+        16 => run::<day16::Day16>(input, parts, explain),
+        17 => run::<day17::Day17>(input, parts, explain),
+        _ => return None,
+    })
+}
+
+/// Puts multi-line answers like rendered letters on lines of their own.
+fn format_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+fn solve_day(day: u32, input: &str, parts: &[u32], explain: bool) -> Result<Outcome, String> {
+    match solve(day, input, parts, explain) {
+        Some(Ok(outcome)) => Ok(outcome),
+        Some(Err(e)) => Err(format!("Failed to parse input for day {}: {}", day, e)),
+        None => Err(format!("No solution for day {}", day)),
+    }
+}
+
+fn run_day(args: &RunArgs) -> Result<(), String> {
+    let input = read_input(args.day, args.input.as_deref())?;
+    let outcome = solve_day(args.day, &input, &args.parts, args.explain)?;
+    for ((part, answer), explanation) in args
+        .parts
+        .iter()
+        .zip(&outcome.answers)
+        .zip(&outcome.explanations)
+    {
+        println!("Solution {}: {}", part, format_answer(answer));
+        if let Some(explanation) = explanation {
+            println!("\n{}", explanation);
+        }
+    }
+    if args.time {
+        print!("\n{}", timings_table(&outcome.timings));
+    }
+    Ok(())
+}
+
+/// Runs every requested day against the stored answers. Returns whether all
+/// of them matched; answers that are not known yet do not count as failures.
+fn verify(args: &VerifyArgs) -> Result<bool, String> {
+    let mut answers = Answers::load(&args.answers)?;
+    let mut all_correct = true;
+    let mut recorded = false;
+    for &day in &args.days {
+        let input = match read_input(day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {} skipped: {}", day, e);
+                continue;
+            }
+        };
+        let results = match solve(day, &input, &[1, 2], false) {
+            Some(Ok(outcome)) => outcome.answers,
+            Some(Err(e)) => {
+                println!("Day {} failed to parse input: {}", day, e);
+                all_correct = false;
+                continue;
+            }
+            None => {
+                println!("Day {} has no solution", day);
+                continue;
+            }
+        };
+        for (part, answer) in (1..).zip(results) {
+            let status = match answers.check(day, part, &answer) {
+                Check::Correct => "ok".to_string(),
+                Check::Wrong(expected) => {
+                    all_correct = false;
+                    format!(
+                        "WRONG, expected {} but got {}",
+                        format_answer(&expected),
+                        format_answer(&answer)
+                    )
+                }
+                Check::Unknown if args.record => {
+                    answers.set(day, part, &answer);
+                    recorded = true;
+                    format!("recorded {}", format_answer(&answer))
+                }
+                Check::Unknown => format!("unknown, got {}", format_answer(&answer)),
+            };
+            println!("Day {} part {}: {}", day, part, status);
+        }
+    }
+    if recorded {
+        answers.save(&args.answers)?;
+    }
+    Ok(all_correct)
+}
+
+/// Runs every requested day `args.runs` times, prints the statistics of
+/// each stage and writes them to `args.output`, as JSON if its name ends in
+/// `.json` and as tab-separated text otherwise.
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let mut results = Vec::new();
+    for &day in &args.days {
+        let input = match read_input(day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {} skipped: {}", day, e);
+                continue;
+            }
+        };
+        let runs = (0..args.runs)
+            .map(|_| solve_day(day, &input, &[1, 2], false).map(|outcome| outcome.timings))
+            .collect::<Result<Vec<Timings>, String>>()?;
+        results.extend(bench_results(day, &runs));
+    }
+    print!("{}", bench_table(&results));
+    let report = if args.output.ends_with(".json") {
+        report_json(&results)
+    } else {
+        report_text(&results)
+    };
+    fs::write(&args.output, report).map_err(|e| format!("Failed to write {}: {}", args.output, e))
+}
+
+fn main() {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            exit(2);
+        }
+    };
+    let result = match command {
+        Command::Run(args) => run_day(&args),
+        Command::Verify(args) => match verify(&args) {
+            Ok(true) => Ok(()),
+            Ok(false) => exit(1),
+            Err(e) => Err(e),
+        },
+        Command::Bench(args) => bench(&args),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_args, solve, BenchArgs, Command, RunArgs, VerifyArgs};
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args("run 15 --part 2 --input path")),
+            Ok(Command::Run(RunArgs {
+                day: 15,
+                parts: vec![2],
+                input: Some("path".to_string()),
+                time: false,
+                explain: false
+            }))
+        );
+        assert_eq!(
+            parse_args(args("run 3 --time")),
+            Ok(Command::Run(RunArgs {
+                day: 3,
+                parts: vec![1, 2],
+                input: None,
+                time: true,
+                explain: false
+            }))
+        );
+        assert_eq!(
+            parse_args(args("verify 14 2 --record")),
+            Ok(Command::Verify(VerifyArgs {
+                days: vec![14, 2],
+                record: true,
+                answers: "answers.toml".to_string()
+            }))
+        );
+        assert!(matches!(
+            parse_args(args("verify --answers a.toml")),
+            Ok(Command::Verify(VerifyArgs { days, .. })) if days.len() == 17
+        ));
+        assert_eq!(
+            parse_args(args("bench 16 --runs 3 --output out.json")),
+            Ok(Command::Bench(BenchArgs {
+                days: vec![16],
+                runs: 3,
+                output: "out.json".to_string()
+            }))
+        );
+        assert!(matches!(
+            parse_args(args("run 16 --explain")),
+            Ok(Command::Run(RunArgs { explain: true, .. }))
+        ));
+        assert!(parse_args(args("bench --runs 0")).is_err());
+        assert!(parse_args(args("run 3 --part 3")).is_err());
+        assert!(parse_args(args("walk 3")).is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", &[1, 2], false)
+                .unwrap()
+                .unwrap()
+                .answers,
+            vec!["7".to_string(), "19".to_string()]
+        );
+        assert_eq!(
+            solve(9, "R 4\nU x\n", &[1], false)
+                .unwrap()
+                .unwrap_err()
+                .line,
+            Some(2)
+        );
+        // Every day parses in the parse stage, so its errors come before any
+        // part runs and its parse time is the time spent parsing.
+        for (day, input, line, column) in [
+            (1, "1000\n\nten\n", 3, 1),
+            (5, "[A]\n 1 \n\nmove 1 from 2 to 1\n", 4, 13),
+            (13, "[1]\n[2,]\n", 2, 3),
+            (17, "<>=\n", 1, 3),
+        ] {
+            let error = solve(day, input, &[1], false).unwrap().unwrap_err();
+            assert_eq!((error.line, error.column), (Some(line), Some(column)));
+        }
+        assert!(solve(26, "", &[1], false).is_none());
+    }
+}
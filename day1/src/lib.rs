@@ -0,0 +1,116 @@
+use utils::{blocks_with, string_lines, try_num_between, ParseError, Solution};
+
+/// Total calories carried by each elf.
+fn parse_calories(lines: impl Iterator<Item = String>) -> Result<Vec<u32>, ParseError> {
+    blocks_with(lines, |block| {
+        block
+            .iter()
+            .enumerate()
+            .map(|(idx, l)| try_num_between::<u32>(l, None, None).map_err(|e| e.on_line(idx + 1)))
+            .sum()
+    })
+    .collect()
+}
+
+fn top_calories(calories: &[u32], count: usize) -> u32 {
+    let mut calories = calories.to_vec();
+    calories.sort_unstable();
+    calories.reverse();
+    calories.truncate(count);
+    calories.iter().sum::<u32>()
+}
+
+pub fn solution2(iter: impl Iterator<Item = String>) -> u32 {
+    top_calories(&parse_calories(iter).expect("Failed to parse calories"), 3)
+}
+
+pub fn solution1(iter: impl Iterator<Item = String>) -> u32 {
+    top_calories(&parse_calories(iter).expect("Failed to parse calories"), 1)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_calories(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        top_calories(input, 1)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        top_calories(input, 3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution1;
+    use crate::solution2;
+    use crate::Day1;
+    use utils::{string_lines, Solution};
+
+    #[test]
+    fn test_solution2() {
+        let test_iter = string_lines(
+            r"
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+",
+        );
+        assert_eq!(solution2(test_iter), 45000)
+    }
+
+    #[test]
+    fn test_solution1() {
+        let test_iter = string_lines(
+            r"
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+",
+        );
+        assert_eq!(solution1(test_iter), 24000);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(solution1(string_lines("")), 0);
+        assert_eq!(solution2(string_lines("")), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day1::parse("1000\n\n2000\nlots\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(4), Some(1)));
+    }
+}
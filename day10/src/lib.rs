@@ -1,8 +1,8 @@
-use std::{convert::Infallible, str::FromStr};
-use utils::{lines, num_between, Point};
+use std::str::FromStr;
+use utils::{string_lines, try_num_between, ParseError, Point, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     Noop,
     Addx(isize),
 }
@@ -31,28 +31,31 @@ impl ExecuteInstruction for Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s == "noop" {
             Ok(Instruction::Noop)
+        } else if s.starts_with("addx ") {
+            Ok(Instruction::Addx(try_num_between(s, Some("addx "), None)?))
         } else {
-            Ok(Instruction::Addx(num_between(s, Some(" "), None) as isize))
+            Err(ParseError::new(1, s, "'noop' or 'addx'"))
         }
     }
 }
 
-fn parse_instructions(lines: impl Iterator<Item = String>) -> Vec<Instruction> {
+fn parse_instructions(lines: impl Iterator<Item = String>) -> Result<Vec<Instruction>, ParseError> {
     lines
-        .map(|l| l.parse().expect("Failed to parse step"))
+        .enumerate()
+        .map(|(idx, l)| l.parse().map_err(|e: ParseError| e.on_line(idx + 1)))
         .collect()
 }
 
-fn solution1(lines: impl Iterator<Item = String>) -> isize {
+fn signal_strength(instructions: &[Instruction]) -> isize {
     let mut cycle = 0;
     let mut register = 1;
     let mut result = 0;
-    for instruction in parse_instructions(lines) {
+    for instruction in instructions {
         for _i in 0..instruction.cycles() {
             cycle += 1;
             if (cycle == 20) || ((cycle - 20) % 40 == 0) {
@@ -72,31 +75,63 @@ fn cycle_to_coord(c: u32) -> Point {
     let col = (c - 1) % 40;
     Point::new(col as isize, row as isize)
 }
- 
-fn solution2(lines: impl Iterator<Item = String>) -> String {
+
+fn render(instructions: &[Instruction]) -> String {
     let mut cycle = 1;
     let mut register: isize = 1;
-    let mut result: Vec<Vec<char>> = std::iter::repeat_with(|| {
-        std::iter::repeat('.').take(40).collect()
-    }).take(6).collect();
-    for instruction in parse_instructions(lines) {
+    let mut result: Vec<Vec<char>> =
+        std::iter::repeat_with(|| std::iter::repeat_n('.', 40).collect())
+            .take(6)
+            .collect();
+    for instruction in instructions {
         for _i in 0..instruction.cycles() {
             let pos = cycle_to_coord(cycle);
-            if (register-1..register+2).contains(&pos.x) {
+            if (register - 1..register + 2).contains(&pos.x) {
                 result[pos.y as usize][pos.x as usize] = '#';
             }
             cycle += 1;
         }
         instruction.execute(&mut register);
     }
-    result.iter().map(|r| {
-        String::from_iter(r)
-    }).collect::<Vec<String>>().join("\n")
+    result
+        .iter()
+        .map(String::from_iter)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> isize {
+    signal_strength(&parse_instructions(lines).expect("Failed to parse instructions"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> String {
+    render(&parse_instructions(lines).expect("Failed to parse instructions"))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Params = ();
+    type Answer1 = isize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        signal_strength(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        render(input)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{solution1, solution2, cycle_to_coord};
+    use crate::{cycle_to_coord, solution1, solution2};
     use utils::{string_lines, Point};
 
     fn test_iter() -> impl Iterator<Item = String> {
@@ -265,16 +300,14 @@ noop",
 
     #[test]
     fn test_solution2() {
-        assert_eq!(solution2(test_iter()), r"##..##..##..##..##..##..##..##..##..##..
+        assert_eq!(
+            solution2(test_iter()),
+            r"##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
 #####.....#####.....#####.....#####.....
 ######......######......######......####
-#######.......#######.......#######.....");
+#######.......#######.......#######....."
+        );
     }
 }
-
-fn main() {
-    println!("Solution 1: {}", solution1(lines("input.txt")));
-    println!("Solution 2: \n{}", solution2(lines("input.txt")));
-}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use utils::{
+    blocks_with, string_lines, try_num_between, try_numbers_on_line, try_str_between, ParseError,
+    Solution,
+};
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Rc<dyn Fn(&u64) -> u64>,
+    divisor: u64,
+    true_monkey: u32,
+    false_monkey: u32,
+}
+
+fn parse_operation(s: &str) -> Rc<dyn Fn(&u64) -> u64> {
+    let split: Vec<String> = s.split_whitespace().map(String::from).collect();
+    if split[2] == "old" {
+        Rc::new(|x: &u64| -> u64 { x * x })
+    } else if split[1] == "+" {
+        let toadd = split[2].parse::<u64>().unwrap();
+        Rc::new(move |x: &u64| -> u64 { x + toadd })
+    } else if split[1] == "*" {
+        let toadd = split[2].parse::<u64>().unwrap();
+        Rc::new(move |x: &u64| -> u64 { x * toadd })
+    } else {
+        panic!("Unable to parse operation {}", s);
+    }
+}
+
+fn parse_monkey(block: &[String]) -> Result<Monkey, ParseError> {
+    let line = |idx: usize| {
+        block
+            .get(idx)
+            .map(String::as_str)
+            .ok_or_else(|| ParseError::new(1, "", "six lines per monkey").on_line(idx + 1))
+    };
+    let starting_items = try_numbers_on_line(line(1)?).map_err(|e| e.on_line(2))?;
+    let operation =
+        parse_operation(try_str_between(line(2)?, Some("new = "), None).map_err(|e| e.on_line(3))?);
+    let divisor =
+        try_num_between(line(3)?, Some("divisible by "), None).map_err(|e| e.on_line(4))?;
+    let true_monkey =
+        try_num_between(line(4)?, Some("to monkey "), None).map_err(|e| e.on_line(5))?;
+    let false_monkey =
+        try_num_between(line(5)?, Some("to monkey "), None).map_err(|e| e.on_line(6))?;
+    Ok(Monkey {
+        items: starting_items,
+        operation,
+        divisor,
+        true_monkey,
+        false_monkey,
+    })
+}
+
+fn numbered_monkeys(monkeys: &[Monkey]) -> HashMap<u32, Monkey> {
+    monkeys
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, monkey)| (i as u32, monkey))
+        .collect()
+}
+
+fn monkey_business(inspections: &HashMap<u32, u32>) -> u64 {
+    let mut inspection_count: Vec<u32> = inspections.values().copied().collect();
+    inspection_count.sort();
+    inspection_count.reverse();
+    let most_active: u64 = inspection_count[0].into();
+    let second_most_active: u64 = inspection_count[1].into();
+    most_active * second_most_active
+}
+
+pub fn solution1(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = numbered_monkeys(monkeys);
+    let mut inspections: HashMap<u32, u32> = HashMap::new();
+    for _round in 0..20 {
+        for i in 0..monkeys.len() as u32 {
+            let current_monkey = monkeys.get_mut(&i).unwrap();
+            let item_count: u32 = current_monkey.items.len().try_into().unwrap();
+            inspections
+                .entry(i)
+                .and_modify(|e| *e += item_count)
+                .or_insert(item_count);
+            let mut to_append: HashMap<u32, Vec<u64>> = HashMap::new();
+            for item in &current_monkey.items {
+                let mut new_item: u64 = (current_monkey.operation)(item);
+                new_item /= 3;
+                if new_item.is_multiple_of(current_monkey.divisor) {
+                    let new_monkey_index: u32 = current_monkey.true_monkey;
+                    to_append
+                        .entry(new_monkey_index)
+                        .and_modify(|v| v.push(new_item))
+                        .or_insert(vec![new_item]);
+                } else {
+                    let new_monkey_index: u32 = current_monkey.false_monkey;
+                    to_append
+                        .entry(new_monkey_index)
+                        .and_modify(|v| v.push(new_item))
+                        .or_insert(vec![new_item]);
+                }
+            }
+            current_monkey.items.clear();
+            for (k, v) in to_append {
+                monkeys.get_mut(&k).unwrap().items.extend(v);
+            }
+        }
+    }
+    monkey_business(&inspections)
+}
+
+pub fn solution2(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = numbered_monkeys(monkeys);
+    let mut inspections: HashMap<u32, u32> = HashMap::new();
+    let generator: u64 = monkeys
+        .values()
+        .map(|m| m.divisor)
+        .reduce(|accum, divisor| accum * divisor)
+        .unwrap();
+    for _round in 0..10000 {
+        for i in 0..monkeys.len() as u32 {
+            let current_monkey = monkeys.get_mut(&i).unwrap();
+            let item_count: u32 = current_monkey.items.len().try_into().unwrap();
+            inspections
+                .entry(i)
+                .and_modify(|e| *e += item_count)
+                .or_insert(item_count);
+            let mut to_append: HashMap<u32, Vec<u64>> = HashMap::new();
+            for item in &current_monkey.items {
+                let new_item = (current_monkey.operation)(item) % generator;
+                if new_item.is_multiple_of(current_monkey.divisor) {
+                    let new_monkey_index: u32 = current_monkey.true_monkey;
+                    to_append
+                        .entry(new_monkey_index)
+                        .and_modify(|v| v.push(new_item))
+                        .or_insert(vec![new_item]);
+                } else {
+                    let new_monkey_index: u32 = current_monkey.false_monkey;
+                    to_append
+                        .entry(new_monkey_index)
+                        .and_modify(|v| v.push(new_item))
+                        .or_insert(vec![new_item]);
+                }
+            }
+            current_monkey.items.clear();
+            for (k, v) in to_append {
+                monkeys.get_mut(&k).unwrap().items.extend(v);
+            }
+        }
+    }
+    monkey_business(&inspections)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        blocks_with(string_lines(input), parse_monkey).collect()
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        solution1(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        solution2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_monkey, solution1, solution2, Day11, Monkey};
+    use utils::{string_blocks, Solution};
+
+    const TEST_INPUT: &str = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    fn test_monkeys() -> Vec<Monkey> {
+        Day11::parse(TEST_INPUT).unwrap()
+    }
+
+    #[test]
+    fn test_parse_monkey() {
+        let block = string_blocks(TEST_INPUT).next().unwrap();
+        let monkey = parse_monkey(&block).unwrap();
+        assert_eq!(monkey.items, Vec::from_iter([79, 98]));
+        assert_eq!((monkey.operation)(&15), 15 * 19);
+        assert_eq!(monkey.divisor, 23);
+        assert_eq!(monkey.true_monkey, 2);
+        assert_eq!(monkey.false_monkey, 3);
+    }
+
+    #[test]
+    fn test_parse_all_monkeys() {
+        let monkeys = test_monkeys();
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(monkeys[0].items, vec![79, 98]);
+        assert_eq!(monkeys[1].items, vec![54, 65, 75, 74]);
+        assert_eq!(monkeys[3].items, vec![74]);
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        let input = TEST_INPUT.replace("divisible by 13", "divisible by x");
+        assert_eq!(Day11::parse(&input).err().and_then(|e| e.line), Some(18));
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(&test_monkeys()), 10605);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(&test_monkeys()), 2713310158);
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use utils::search::bfs;
+use utils::{
+    string_lines, Grid, Neighbourhood, ParseError, Point, PointMap, PointMappable, Solution,
+    Topology,
+};
+
+fn height(c: char) -> Option<u32> {
+    match c {
+        'S' => Some(0),
+        'E' => Some(26),
+        c if c.is_ascii_lowercase() => Some((c as u32) - 97),
+        _ => None,
+    }
+}
+
+/// The heights of the area, with where the climb starts and ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeightMap {
+    start: Point,
+    end: Point,
+    heights: PointMap,
+}
+
+fn parse_heights(lines: impl Iterator<Item = String>) -> Result<HeightMap, ParseError> {
+    let map = Grid::try_from_chars(
+        lines,
+        |c| height(c).map(|_| c),
+        "a height from 'a' to 'z', 'S' or 'E'",
+    )?;
+    let start = map
+        .position(|&c| c == 'S')
+        .ok_or_else(|| ParseError::new(1, "", "a start point marked 'S'"))?;
+    let end = map
+        .position(|&c| c == 'E')
+        .ok_or_else(|| ParseError::new(1, "", "an end point marked 'E'"))?;
+    Ok(HeightMap {
+        start,
+        end,
+        heights: map.map(|&c| height(c).unwrap_or_default()),
+    })
+}
+
+/// The points next to `p` that can be reached from it in one step.
+fn reachable<'a>(
+    heights: &'a PointMap,
+    p: &Point,
+    is_adjacent: fn(u32, u32) -> bool,
+) -> impl Iterator<Item = Point> + 'a {
+    let p_height = heights.at(p);
+    heights
+        .neighbours(p, Neighbourhood::VonNeumann, Topology::Bounded)
+        .filter(move |adjacent| is_adjacent(p_height, heights.at(adjacent)))
+}
+
+fn solution_1_adjacency(from_height: u32, to_height: u32) -> bool {
+    to_height <= from_height + 1
+}
+
+fn shortest_climb(map: &HeightMap) -> u32 {
+    let path = bfs(
+        map.start,
+        |p| reachable(&map.heights, p, solution_1_adjacency),
+        |p: &Point| *p == map.end,
+    )
+    .expect("No path found.");
+    path.distance as u32
+}
+
+fn solution_2_adjacency(from_height: u32, to_height: u32) -> bool {
+    solution_1_adjacency(to_height, from_height)
+}
+
+fn shortest_hike(map: &HeightMap) -> u32 {
+    let possible_end_points: HashSet<Point> = map
+        .heights
+        .all_points()
+        .filter(|p| map.heights.at(p) == 0)
+        .collect();
+    let path = bfs(
+        map.end,
+        |p| reachable(&map.heights, p, solution_2_adjacency),
+        &possible_end_points,
+    )
+    .expect("No path found.");
+    path.distance as u32
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    shortest_climb(&parse_heights(lines).expect("Invalid height map"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    shortest_hike(&parse_heights(lines).expect("Invalid height map"))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_heights(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        shortest_climb(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        shortest_hike(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{parse_heights, reachable, solution1, solution2, solution_1_adjacency, Day12};
+    use utils::{string_lines, Point, Solution};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+        )
+    }
+
+    #[test]
+    fn test_parse_heights() {
+        let map = parse_heights(test_iter()).unwrap();
+        let (start, end, heights) = (map.start, map.end, map.heights);
+        assert_eq!(start, Point::new(0, 0));
+        assert_eq!(end, Point::new(5, 2));
+        assert_eq!(
+            reachable(&heights, &start, solution_1_adjacency).collect::<HashSet<_>>(),
+            HashSet::from([Point::new(1, 0), Point::new(0, 1)])
+        );
+        assert_eq!(
+            reachable(&heights, &end, solution_1_adjacency).collect::<HashSet<_>>(),
+            HashSet::from([
+                Point::new(5, 1),
+                Point::new(4, 2),
+                Point::new(6, 2),
+                Point::new(5, 3)
+            ])
+        );
+        assert_eq!(
+            reachable(&heights, &Point::new(5, 1), solution_1_adjacency).count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), 31);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), 29);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day12::parse("Sab\naB E\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        assert!(Day12::parse("abc\nabE\n").is_err());
+    }
+}
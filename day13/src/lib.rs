@@ -4,16 +4,18 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::char,
-    character::complete::{newline, u32},
-    combinator::map,
-    multi::{separated_list0, separated_list1},
-    sequence::{delimited, tuple},
+    character::complete::u32,
+    combinator::{cut, map},
+    multi::separated_list0,
+    sequence::delimited,
     IResult,
 };
-use std::{cmp::Ordering, fs::read_to_string};
+use std::cmp::Ordering;
+use utils::{blocks_with, nom_line, string_lines, ParseError, Solution};
 
-#[derive(Eq, PartialEq, Clone)]
-enum Data {
+#[allow(clippy::vec_box)]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Data {
     Value(u32),
     Data(Vec<Box<Data>>),
 }
@@ -22,49 +24,56 @@ impl Data {
     fn values(v: &[u32]) -> Self {
         Data::Data(v.iter().map(|v| Box::new(Data::Value(*v))).collect())
     }
+    #[allow(clippy::self_named_constructors)]
     fn data(d: Vec<Data>) -> Self {
-        Data::Data(d.into_iter().map(|d| Box::new(d)).collect())
+        Data::Data(d.into_iter().map(Box::new).collect())
     }
 }
 
 fn box_vec(v: Vec<Data>) -> Data {
-    Data::Data(v.into_iter().map(|d| Box::new(d)).collect())
+    Data::Data(v.into_iter().map(Box::new).collect())
 }
 
 fn parse_line(line: &str) -> IResult<&str, Data> {
-    let parse_value = map(u32, |u| Data::Value(u));
+    let parse_value = map(u32, Data::Value);
     let mut parse_list = alt((
         parse_value,
         delimited(
             char('['),
             map(separated_list0(tag(","), parse_line), box_vec),
-            char(']'),
+            cut(char(']')),
         ),
     ));
     parse_list(line)
 }
 
-fn parse_data_pair(text: &str) -> IResult<&str, (Data, Data)> {
-    let (rest, (first, _, second, _)) = tuple((parse_line, newline, parse_line, newline))(text)?;
-    Ok((rest, (first, second)))
+/// Parses a block of two lines, with a packet on each.
+fn parse_data_pair(block: &[String]) -> Result<(Data, Data), ParseError> {
+    let packet =
+        |idx: usize| nom_line(&block[idx], parse_line, "a packet").map_err(|e| e.on_line(idx + 1));
+    match block.len() {
+        1 => Err(ParseError::new(1, &block[0], "a pair of packets")),
+        2 => Ok((packet(0)?, packet(1)?)),
+        _ => Err(ParseError::new(1, &block[2], "a blank line after a pair").on_line(3)),
+    }
 }
 
-fn parse_input(text: &str) -> IResult<&str, Vec<(Data, Data)>> {
-    separated_list1(newline, parse_data_pair)(text)
+fn parse_packets(text: &str) -> Result<Vec<(Data, Data)>, ParseError> {
+    blocks_with(string_lines(text), parse_data_pair).collect()
 }
 
 fn compare(left: &Data, right: &Data) -> Ordering {
     match (left, right) {
-        (Data::Value(l), Data::Value(r)) => l.cmp(&r),
+        (Data::Value(l), Data::Value(r)) => l.cmp(r),
         (Data::Value(l), Data::Data(r)) => {
             compare(&Data::Data(Vec::from([Box::new(Data::Value(*l))])), right)
         }
         (Data::Data(l), Data::Value(r)) => {
             compare(left, &Data::Data(Vec::from([Box::new(Data::Value(*r))])))
         }
-        (Data::Data(l), Data::Data(r)) if l.len() == 0 && r.len() == 0 => Ordering::Equal,
-        (Data::Data(l), Data::Data(r)) if l.len() == 0 => Ordering::Less,
-        (Data::Data(l), Data::Data(r)) if r.len() == 0 => Ordering::Greater,
+        (Data::Data(l), Data::Data(r)) if l.is_empty() && r.is_empty() => Ordering::Equal,
+        (Data::Data(l), Data::Data(r)) if l.is_empty() => Ordering::Less,
+        (Data::Data(l), Data::Data(r)) if r.is_empty() => Ordering::Greater,
         (Data::Data(l), Data::Data(r)) => {
             for (l_elem, r_elem) in l.iter().zip(r.iter()) {
                 let order = compare(l_elem, r_elem);
@@ -73,17 +82,17 @@ fn compare(left: &Data, right: &Data) -> Ordering {
                 }
             }
             if l.len() < r.len() {
-                return Ordering::Less;
+                Ordering::Less
             } else if r.len() < l.len() {
-                return Ordering::Greater;
+                Ordering::Greater
             } else {
-                return Ordering::Equal;
+                Ordering::Equal
             }
         }
     }
 }
 
-fn solution_1_calc(d: Vec<(Data, Data)>) -> u32 {
+fn solution_1_calc(d: &[(Data, Data)]) -> u32 {
     let mut result: u32 = 0;
     for (idx, (l, r)) in d.iter().enumerate() {
         if compare(l, r) == Ordering::Less {
@@ -93,22 +102,18 @@ fn solution_1_calc(d: Vec<(Data, Data)>) -> u32 {
     result
 }
 
-fn solution1(input: &str) -> u32 {
-    match parse_input(input) {
-        Ok((rest, pairs)) => solution_1_calc(pairs),
-        Err(e) => panic!("{}", e),
-    }
+pub fn solution1(input: &str) -> u32 {
+    solution_1_calc(&parse_packets(input).expect("Failed to parse packets"))
 }
 
-fn flatten_pairs(v: Vec<(Data, Data)>) -> Vec<Data> {
-    v.into_iter().flat_map(|(d1, d2)| [d1, d2]).collect()
+fn flatten_pairs(v: &[(Data, Data)]) -> Vec<Data> {
+    v.iter()
+        .flat_map(|(d1, d2)| [d1.clone(), d2.clone()])
+        .collect()
 }
 
-fn solution2(input: &str) -> u32 {
-    let mut data = match parse_input(input) {
-        Ok((rest, pairs)) => flatten_pairs(pairs),
-        Err(e) => panic!("{}", e),
-    };
+fn decoder_key(pairs: &[(Data, Data)]) -> u32 {
+    let mut data = flatten_pairs(pairs);
     let marker_first = Data::data(vec![Data::values(&[2])]);
     let marker_second = Data::data(vec![Data::values(&[6])]);
     data.push(marker_first.clone());
@@ -119,11 +124,37 @@ fn solution2(input: &str) -> u32 {
     (first_pos as u32) * (second_pos as u32)
 }
 
+pub fn solution2(input: &str) -> u32 {
+    decoder_key(&parse_packets(input).expect("Failed to parse packets"))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Data, Data)>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_packets(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        solution_1_calc(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        decoder_key(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::cmp::Ordering;
 
-    use crate::{compare, parse_data_pair, parse_input, parse_line, solution1, solution2};
+    use crate::{compare, parse_line, parse_packets, solution1, solution2, Day13};
+    use utils::Solution;
 
     const TEST_STRING: &str = r"[1,1,3,1,1]
 [1,1,5,1,1]
@@ -162,8 +193,8 @@ mod test {
     }
 
     fn compare_pair(input: &str) -> Ordering {
-        let (left, right) = parse_data_pair(input).unwrap().1;
-        compare(&left, &right)
+        let (left, right) = &parse_packets(input).unwrap()[0];
+        compare(left, right)
     }
 
     #[test]
@@ -171,9 +202,9 @@ mod test {
         let test_string = r"[1,1,3,1,1]
 [1,1,5,1,1]
 ";
-        match parse_data_pair(test_string) {
-            Ok((rest, output)) => {
-                assert!(rest.is_empty());
+        match parse_packets(test_string) {
+            Ok(output) => {
+                assert_eq!(output.len(), 1);
             }
             Err(e) => {
                 panic!("{}", e);
@@ -183,9 +214,8 @@ mod test {
 
     #[test]
     fn test_parse_solution() {
-        match parse_input(TEST_STRING) {
-            Ok((rest, output)) => {
-                assert!(rest.is_empty());
+        match parse_packets(TEST_STRING) {
+            Ok(output) => {
                 assert_eq!(output.len(), 8);
             }
             Err(e) => {
@@ -218,15 +248,11 @@ mod test {
     fn test_solution2() {
         assert_eq!(solution2(TEST_STRING), 140);
     }
-}
 
-fn main() {
-    println!(
-        "Solution 1: {}",
-        solution1(&read_to_string("input.txt").unwrap())
-    );
-    println!(
-        "Solution 2: {}",
-        solution2(&read_to_string("input.txt").unwrap())
-    );
+    #[test]
+    fn test_parse_error() {
+        let error = Day13::parse("[1,2]\n[3]\n\n[[1],[2,x]]\n[4]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(4), Some(8)));
+        assert_eq!(Day13::parse("[1]\n[2]\n[3]\n").unwrap_err().line, Some(3));
+    }
 }
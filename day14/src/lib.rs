@@ -2,28 +2,21 @@
 extern crate nom;
 
 use nom::{
-    bytes::complete::tag,
-    character::complete::{newline, u32},
-    multi::{many1, separated_list1},
-    sequence::{separated_pair, terminated},
-    IResult,
+    bytes::complete::tag, character::complete::u32, multi::separated_list1,
+    sequence::separated_pair, IResult,
+};
+use utils::{
+    lines_with, nom_line, string_lines, Direction, Line, ParseError, Point, Solution, SparseMap,
 };
-use std::fs::read_to_string;
-use utils::{Point, SparseMap};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 enum SpaceType {
     Sand,
     Rock,
+    #[default]
     Empty,
 }
 
-impl Default for SpaceType {
-    fn default() -> Self {
-        Self::Empty
-    }
-}
-
 struct GroundMap {
     map: SparseMap<SpaceType>,
 }
@@ -40,11 +33,18 @@ impl GroundMap {
     }
 
     fn add_rock_between(&mut self, p1: &Point, p2: &Point) {
-        for path_point in p1.orthogonal_range(&p2) {
+        for path_point in Line::new(p1, p2).points() {
             self.map.put(&path_point, SpaceType::Rock);
         }
     }
 
+    /// Draws rock along every segment of a path through `points`.
+    fn add_rock_path(&mut self, points: &[Point]) {
+        for segment in points.windows(2) {
+            self.add_rock_between(&segment[0], &segment[1]);
+        }
+    }
+
     fn has_left(&self, p: &Point) -> bool {
         let (pmin, pmax) = self.map.bounds();
         p.x < pmin.x || p.x > pmax.x || p.y > pmax.y
@@ -84,27 +84,28 @@ fn parse_coord(i: &str) -> IResult<&str, (u32, u32)> {
 }
 
 fn parse_line(text: &str) -> IResult<&str, Vec<(u32, u32)>> {
-    terminated(separated_list1(tag(" -> "), parse_coord), newline)(text)
+    separated_list1(tag(" -> "), parse_coord)(text)
 }
 
-fn parse_input(text: &str) -> IResult<&str, Vec<Vec<(u32, u32)>>> {
-    many1(parse_line)(text)
+fn parse_input(text: &str) -> Result<Vec<Vec<(u32, u32)>>, ParseError> {
+    lines_with(string_lines(text), |line| {
+        nom_line(line, parse_line, "a path like '498,4 -> 498,6'")
+    })
 }
 
-fn construct_map(rock: Vec<Vec<(u32, u32)>>) -> GroundMap {
+fn construct_map(rock: &[Vec<(u32, u32)>]) -> GroundMap {
     let mut result = GroundMap::new();
     for path in rock {
-        for i in 1..path.len() {
-            result.add_rock_between(
-                &Point::new(path[i - 1].0 as isize, path[i - 1].1 as isize),
-                &Point::new(path[i].0 as isize, path[i].1 as isize),
-            )
-        }
+        let points: Vec<Point> = path
+            .iter()
+            .map(|&(x, y)| Point::new(x as isize, y as isize))
+            .collect();
+        result.add_rock_path(&points);
     }
     result
 }
 
-fn construct_map_solution2(rock: Vec<Vec<(u32, u32)>>) -> GroundMap {
+fn construct_map_solution2(rock: &[Vec<(u32, u32)>]) -> GroundMap {
     let mut result = construct_map(rock);
     let floor_y = result.bounds().1.y + 2;
     result.add_rock_between(
@@ -116,14 +117,14 @@ fn construct_map_solution2(rock: Vec<Vec<(u32, u32)>>) -> GroundMap {
 
 fn points_to_check(p: &Point) -> [Point; 3] {
     [
-        *p + Point::new(0, 1),
-        *p + Point::new(-1, 1),
-        *p + Point::new(1, 1),
+        p.step(Direction::South, 1),
+        p.step(Direction::SouthWest, 1),
+        p.step(Direction::SouthEast, 1),
     ]
 }
 
 fn drop_sand(m: &GroundMap, drop_point: &Point) -> (Option<Point>, bool) {
-    if m.is_solid(&drop_point) {
+    if m.is_solid(drop_point) {
         return (None, true);
     }
     let mut current_point = *drop_point;
@@ -144,9 +145,8 @@ fn drop_sand(m: &GroundMap, drop_point: &Point) -> (Option<Point>, bool) {
     }
 }
 
-fn solution1(input: &str) -> u32 {
-    let (_, input) = parse_input(input).unwrap();
-    let mut m = construct_map(input);
+fn sand_until_abyss(rock: &[Vec<(u32, u32)>]) -> u32 {
+    let mut m = construct_map(rock);
     let mut dropped = 0;
     while let (Some(p), _) = drop_sand(&m, &Point::new(500, 0)) {
         m.put_sand(&p);
@@ -155,9 +155,8 @@ fn solution1(input: &str) -> u32 {
     dropped
 }
 
-fn solution2(input: &str) -> u32 {
-    let (_, input) = parse_input(input).unwrap();
-    let mut m = construct_map_solution2(input);
+fn sand_until_blocked(rock: &[Vec<(u32, u32)>]) -> u32 {
+    let mut m = construct_map_solution2(rock);
     let mut dropped = 0;
     loop {
         let (p, blocked) = drop_sand(&m, &Point::new(500, 0));
@@ -172,13 +171,42 @@ fn solution2(input: &str) -> u32 {
     }
 }
 
+pub fn solution1(input: &str) -> u32 {
+    sand_until_abyss(&parse_input(input).expect("Failed to parse rock paths"))
+}
+
+pub fn solution2(input: &str) -> u32 {
+    sand_until_blocked(&parse_input(input).expect("Failed to parse rock paths"))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<(u32, u32)>>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        sand_until_abyss(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        sand_until_blocked(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use utils::Point;
+    use utils::{Point, Solution};
 
     use crate::{
         construct_map, construct_map_solution2, drop_sand, parse_input, parse_line, solution1,
-        solution2,
+        solution2, Day14,
     };
 
     const TEST_STRING: &str = r"498,4 -> 498,6 -> 496,6
@@ -187,7 +215,7 @@ mod test {
 
     #[test]
     fn test_parse_line() {
-        let test_string = "498,4 -> 498,6 -> 496,6\n";
+        let test_string = "498,4 -> 498,6 -> 496,6";
         match parse_line(test_string) {
             Ok((rest, result)) => {
                 assert_eq!(result, vec![(498, 4), (498, 6), (496, 6)]);
@@ -201,8 +229,7 @@ mod test {
     #[test]
     fn test_parse_input() {
         match parse_input(TEST_STRING) {
-            Ok((rest, output)) => {
-                assert!(rest.is_empty());
+            Ok(output) => {
                 assert_eq!(output.len(), 2);
                 assert_eq!(output[0], vec![(498, 4), (498, 6), (496, 6)]);
                 assert_eq!(output[1], vec![(503, 4), (502, 4), (502, 9), (494, 9)]);
@@ -219,20 +246,28 @@ mod test {
             vec![(498, 4), (498, 6), (496, 6)],
             vec![(503, 4), (502, 4), (502, 9), (494, 9)],
         ];
-        let map = construct_map(input);
+        let map = construct_map(&input);
         assert!(map.is_solid(&Point::new(498, 4)));
         assert!(map.is_solid(&Point::new(495, 9)));
         assert!(!map.is_solid(&Point::new(494, 0)));
         assert_eq!(map.bounds(), (Point::new(494, 4), Point::new(503, 9)));
     }
 
+    #[test]
+    fn test_diagonal_rock() {
+        let map = construct_map(&[vec![(498, 4), (500, 6), (504, 4)]]);
+        assert!(map.is_solid(&Point::new(499, 5)));
+        assert!(map.is_solid(&Point::new(502, 5)));
+        assert!(!map.is_solid(&Point::new(499, 4)));
+    }
+
     #[test]
     fn test_map_construction_solution2() {
         let input = vec![
             vec![(498, 4), (498, 6), (496, 6)],
             vec![(503, 4), (502, 4), (502, 9), (494, 9)],
         ];
-        let map = construct_map_solution2(input);
+        let map = construct_map_solution2(&input);
         assert!(map.is_solid(&Point::new(500, 11)));
         assert!(map.is_solid(&Point::new(489, 11)));
         assert!(map.is_solid(&Point::new(511, 11)));
@@ -242,8 +277,8 @@ mod test {
 
     #[test]
     fn test_sand_drop() {
-        let (_, output) = parse_input(TEST_STRING).unwrap();
-        let map = construct_map(output);
+        let output = parse_input(TEST_STRING).unwrap();
+        let map = construct_map(&output);
         assert_eq!(
             drop_sand(&map, &Point::new(500, 0)),
             (Some(Point::new(500, 8)), false)
@@ -259,16 +294,10 @@ mod test {
     fn test_solution2() {
         assert_eq!(solution2(TEST_STRING), 93);
     }
-}
 
-fn main() {
-    println!(
-        "Solution 1: {}",
-        solution1(&read_to_string("input.txt").unwrap())
-    );
-    // Should be 23610
-    println!(
-        "Solution 2: {}",
-        solution2(&read_to_string("input.txt").unwrap())
-    );
+    #[test]
+    fn test_parse_error() {
+        let error = Day14::parse("498,4 -> 498,6\n503,4 -> 502\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(6)));
+    }
 }
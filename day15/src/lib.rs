@@ -2,18 +2,17 @@ extern crate nom;
 
 use nom::{
     bytes::complete::tag,
-    character::complete::{i32, newline},
+    character::complete::i32,
     combinator::map,
-    multi::separated_list1,
     sequence::{preceded, tuple},
     IResult,
 };
 use rayon::prelude::*;
-use std::{collections::HashSet, fs::read_to_string};
-use utils::{Point, SparseMap};
+use std::collections::HashSet;
+use utils::{lines_with, nom_line, string_lines, ParseError, Point, Solution, SparseMap};
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
-struct BeaconInfo {
+pub struct BeaconInfo {
     sensor_pos: Point,
     beacon_pos: Point,
 }
@@ -21,22 +20,17 @@ struct BeaconInfo {
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 struct NegativeBeaconInfo {
     sensor_pos: Point,
-    dist: u32,
+    dist: u64,
 }
 
-#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default)]
 enum MapState {
+    #[default]
     Empty,
     Beacon,
     Sensor,
 }
 
-impl Default for MapState {
-    fn default() -> Self {
-        MapState::Empty
-    }
-}
-
 struct BeaconMap {
     map: SparseMap<MapState>,
 }
@@ -86,25 +80,26 @@ fn parse_beacon_info(input: &str) -> IResult<&str, BeaconInfo> {
         )),
         |(sensor_x, sensor_y, beacon_x, beacon_y)| {
             BeaconInfo::new(
-                sensor_x.try_into().unwrap(),
-                sensor_y.try_into().unwrap(),
-                beacon_x.try_into().unwrap(),
-                beacon_y.try_into().unwrap(),
+                sensor_x as isize,
+                sensor_y as isize,
+                beacon_x as isize,
+                beacon_y as isize,
             )
         },
     )(input)
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<BeaconInfo>> {
-    separated_list1(newline, parse_beacon_info)(input)
+fn parse_input(input: &str) -> Result<Vec<BeaconInfo>, ParseError> {
+    lines_with(string_lines(input), |line| {
+        nom_line(line, parse_beacon_info, "a sensor and its closest beacon")
+    })
 }
 
-fn solution1(input: &str, row: i32) -> u32 {
-    let all_info = parse_input(input).unwrap().1;
+fn covered_in_row(all_info: &[BeaconInfo], row: i32) -> u32 {
     let mut beacon_map = BeaconMap::new();
     let mut negative_info = Vec::new();
     for info in all_info {
-        beacon_map.put_beacon_info(&info);
+        beacon_map.put_beacon_info(info);
         negative_info.push(NegativeBeaconInfo {
             sensor_pos: info.sensor_pos,
             dist: info.sensor_pos.dist(&info.beacon_pos),
@@ -130,12 +125,11 @@ fn solution1(input: &str, row: i32) -> u32 {
     result
 }
 
-fn solution2(input: &str, bounds: isize) -> isize {
-    let all_info = parse_input(input).unwrap().1;
+fn tuning_frequency(all_info: &[BeaconInfo], bounds: isize) -> isize {
     let mut negative_info: Vec<NegativeBeaconInfo> = Vec::new();
     let mut beacon_map = BeaconMap::new();
     for bi in all_info {
-        beacon_map.put_beacon_info(&bi);
+        beacon_map.put_beacon_info(bi);
         negative_info.push(NegativeBeaconInfo {
             sensor_pos: bi.sensor_pos,
             dist: bi.sensor_pos.dist(&bi.beacon_pos),
@@ -162,9 +156,56 @@ fn solution2(input: &str, bounds: isize) -> isize {
     result.x * 4000000 + result.y
 }
 
+pub fn solution1(input: &str, row: i32) -> u32 {
+    covered_in_row(&parse_input(input).expect("Failed to parse sensors"), row)
+}
+
+pub fn solution2(input: &str, bounds: isize) -> isize {
+    tuning_frequency(
+        &parse_input(input).expect("Failed to parse sensors"),
+        bounds,
+    )
+}
+
+pub struct Params {
+    pub row: i32,
+    pub bounds: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            bounds: 4000000,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<BeaconInfo>;
+    type Params = Params;
+    type Answer1 = u32;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer1 {
+        covered_in_row(input, params.row)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer2 {
+        tuning_frequency(input, params.bounds)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{parse_beacon_info, parse_input, solution1, solution2, BeaconInfo};
+    use crate::{parse_beacon_info, parse_input, solution1, solution2, BeaconInfo, Day15, Params};
+    use utils::Solution;
 
     const TEST_STRING: &str = r"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
@@ -190,7 +231,7 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
     #[test]
     fn test_parse_input() {
-        let (_, result) = parse_input(TEST_STRING).unwrap();
+        let result = parse_input(TEST_STRING).unwrap();
         assert_eq!(
             result,
             vec![
@@ -221,15 +262,23 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3";
     fn test_solution2() {
         assert_eq!(solution2(TEST_STRING, 20), 56000011);
     }
-}
 
-fn main() {
-    println!(
-        "Solution 1: {}",
-        solution1(&read_to_string("input.txt").unwrap(), 2000000)
-    );
-    println!(
-        "Solution 2: {}",
-        solution2(&read_to_string("input.txt").unwrap(), 4000000)
-    );
+    #[test]
+    fn test_solution_params() {
+        let input = Day15::parse(TEST_STRING).unwrap();
+        let params = Params {
+            row: 10,
+            bounds: 20,
+        };
+        assert_eq!(Day15::part1(&input, &params), 26);
+        assert_eq!(Day15::part2(&input, &params), 56000011);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input =
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16: beacon\n";
+        let error = Day15::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(20)));
+    }
 }
@@ -0,0 +1,626 @@
+extern crate nom;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, u32},
+    combinator::{map, opt},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use std::{
+    cell::OnceCell,
+    cmp::max,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use utils::graph::{DistanceMatrix, Graph};
+use utils::search::bfs;
+use utils::{lines_with, nom_line, string_lines, BitSet, Memo, ParseError, Solution};
+
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+struct Edge {
+    from: String,
+    rate: u32,
+    to: String,
+}
+
+impl Edge {
+    fn new(from: &str, rate: u32, to: &str) -> Self {
+        Self {
+            from: from.to_owned(),
+            rate,
+            to: to.to_owned(),
+        }
+    }
+}
+
+fn parse_edge(input: &str) -> IResult<&str, HashSet<Edge>> {
+    let tunnel_str = tuple((
+        tag("; tunnel"),
+        opt(tag("s")),
+        tag(" lead"),
+        opt(tag("s")),
+        tag(" to valve"),
+        opt(tag("s")),
+        tag(" "),
+    ));
+    map(
+        tuple((
+            preceded(tag("Valve "), alpha1),
+            preceded(tag(" has flow rate="), u32),
+            preceded(tunnel_str, separated_list1(tag(", "), alpha1)),
+        )),
+        |(from, rate, to)| {
+            to.iter()
+                .map(|to| Edge::new(from, rate, to))
+                .collect::<HashSet<Edge>>()
+        },
+    )(input)
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Edge>, ParseError> {
+    let parsed = lines_with(string_lines(input), |line| {
+        nom_line(line, parse_edge, "a valve and the tunnels from it")
+    })?;
+    Ok(parsed.into_iter().flatten().collect())
+}
+
+/// Every valve with an edge to each valve a tunnel leads to.
+fn tunnel_graph(es: &HashSet<Edge>) -> Graph {
+    let mut tunnels = Graph::new();
+    for e in es {
+        tunnels.add_edge(&e.from, &e.to, 1);
+    }
+    tunnels
+}
+
+/// The graph of the start valve and the valves with a non-zero flow rate,
+/// with edges weighted by the time it takes to walk between them, and the
+/// flow rates by valve id.
+fn valve_graph(es: &HashSet<Edge>, start: &str) -> (Graph, Vec<u32>) {
+    let tunnels = tunnel_graph(es);
+    let rates: HashMap<&str, u32> = es.iter().map(|e| (e.from.as_str(), e.rate)).collect();
+    let valves = tunnels.compress(|id| {
+        let name = tunnels.name(id);
+        name == start || rates.get(name).unwrap_or(&0) > &0
+    });
+    let rates = valves
+        .ids()
+        .map(|id| *rates.get(valves.name(id)).unwrap_or(&0))
+        .collect();
+    (valves, rates)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Move(String),
+    Open(String),
+}
+
+/// What happens in one minute of a plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Minute {
+    pub minute: u32,
+    /// The valves open during this minute, in alphabetical order.
+    pub open: Vec<String>,
+    /// The pressure the open valves release during this minute.
+    pub releasing: u32,
+    pub action: Option<Action>,
+    /// The pressure released so far, including this minute.
+    pub released: u32,
+}
+
+/// A schedule of moves and valve openings, minute by minute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub minutes: Vec<Minute>,
+}
+
+impl Plan {
+    pub fn released(&self) -> u32 {
+        self.minutes.last().map_or(0, |m| m.released)
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, minute) in self.minutes.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "== Minute {} ==", minute.minute)?;
+            match minute.open.as_slice() {
+                [] => writeln!(f, "No valves are open.")?,
+                [valve] => writeln!(
+                    f,
+                    "Valve {} is open, releasing {} pressure.",
+                    valve, minute.releasing
+                )?,
+                [first, second] => writeln!(
+                    f,
+                    "Valves {} and {} are open, releasing {} pressure.",
+                    first, second, minute.releasing
+                )?,
+                [rest @ .., last] => writeln!(
+                    f,
+                    "Valves {}, and {} are open, releasing {} pressure.",
+                    rest.join(", "),
+                    last,
+                    minute.releasing
+                )?,
+            }
+            match &minute.action {
+                Some(Action::Move(valve)) => writeln!(f, "You move to valve {}.", valve)?,
+                Some(Action::Open(valve)) => writeln!(f, "You open valve {}.", valve)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Position, remaining time and opened valves.
+type State = (usize, u32, BitSet);
+
+struct MaxPressureCalculator {
+    valves: Graph,
+    pressures: Vec<u32>,
+    distances: DistanceMatrix,
+}
+
+impl MaxPressureCalculator {
+    fn new(valves: Graph, pressures: Vec<u32>) -> Self {
+        assert!(
+            valves.len() <= BitSet::<u64>::CAPACITY,
+            "Too many valves with a flow rate"
+        );
+        Self {
+            distances: valves.floyd_warshall(),
+            valves,
+            pressures,
+        }
+    }
+
+    /// The highest pressure that can be released from `pos` in
+    /// `remaining_time` by opening valves not in `opened`.
+    fn get_maximum_released_pressure_from(
+        &self,
+        pos: usize,
+        remaining_time: u32,
+        opened: BitSet,
+        cache: &mut Memo<State, u32>,
+    ) -> u32 {
+        cache.get_or_compute((pos, remaining_time, opened), |cache| {
+            self.next_valves(pos, remaining_time, opened)
+                .map(|(next, time_after_opening)| {
+                    self.get_maximum_released_pressure_from(
+                        next,
+                        time_after_opening,
+                        opened.with(next),
+                        cache,
+                    ) + time_after_opening * self.pressures[next]
+                })
+                .max()
+                .unwrap_or(0)
+        })
+    }
+
+    /// The valves still closed that can be reached and opened from `pos`
+    /// with time left for them to release pressure, and the time left once
+    /// each of them is open.
+    fn next_valves(
+        &self,
+        pos: usize,
+        remaining_time: u32,
+        opened: BitSet,
+    ) -> impl Iterator<Item = (usize, u32)> + '_ {
+        self.valves.ids().filter_map(move |next| {
+            if opened.contains(next) || self.pressures[next] == 0 {
+                return None;
+            }
+            let distance_to_next = self.distances.get(pos, next)?;
+            remaining_time
+                .checked_sub(distance_to_next + 1)
+                .filter(|&time_after_opening| time_after_opening > 0)
+                .map(|time_after_opening| (next, time_after_opening))
+        })
+    }
+
+    /// The highest pressure that can be released and the order in which to
+    /// open valves to release it, read back from the memoised search.
+    fn get_best_order(&self, start: &str, time: u32) -> (u32, Vec<usize>) {
+        let mut pos = self.valves.id(start).expect("Unknown start valve");
+        let mut remaining_time = time;
+        let mut opened = BitSet::new();
+        let mut cache = Memo::new();
+        let best = self.get_maximum_released_pressure_from(pos, remaining_time, opened, &mut cache);
+        let mut left = best;
+        let mut order = Vec::new();
+        while left > 0 {
+            let (next, time_after_opening, rest) = self
+                .next_valves(pos, remaining_time, opened)
+                .find_map(|(next, time_after_opening)| {
+                    let rest = self.get_maximum_released_pressure_from(
+                        next,
+                        time_after_opening,
+                        opened.with(next),
+                        &mut cache,
+                    );
+                    (rest + time_after_opening * self.pressures[next] == left).then_some((
+                        next,
+                        time_after_opening,
+                        rest,
+                    ))
+                })
+                .expect("The best pressure comes from some valve");
+            order.push(next);
+            pos = next;
+            remaining_time = time_after_opening;
+            opened = opened.with(next);
+            left = rest;
+        }
+        (best, order)
+    }
+
+    /// The plan for a single agent opening the valves of `order`, as found by
+    /// `get_best_order`, walking through `tunnels`, the uncompressed graph of
+    /// the valves.
+    fn get_plan(&self, tunnels: &Graph, start: &str, time: u32, order: &[usize]) -> Plan {
+        let mut actions = Vec::new();
+        let mut pos = tunnels.id(start).expect("Unknown start valve");
+        for &valve in order {
+            let name = self.valves.name(valve);
+            let target = tunnels.id(name).expect("Valve without tunnels");
+            let path = bfs(
+                pos,
+                |&id| tunnels.edges(id).iter().map(|&(to, _)| to),
+                |&id: &usize| id == target,
+            )
+            .expect("Valve can be reached");
+            for &step in &path.path[1..] {
+                actions.push(Action::Move(tunnels.name(step).to_string()));
+            }
+            actions.push(Action::Open(name.to_string()));
+            pos = target;
+        }
+
+        let mut open: Vec<(String, u32)> = Vec::new();
+        let mut released = 0;
+        let mut minutes = Vec::new();
+        let mut actions = actions.into_iter();
+        for minute in 1..=time {
+            let releasing = open.iter().map(|(_, rate)| rate).sum();
+            released += releasing;
+            let action = actions.next();
+            minutes.push(Minute {
+                minute,
+                open: open.iter().map(|(name, _)| name.clone()).collect(),
+                releasing,
+                action: action.clone(),
+                released,
+            });
+            if let Some(Action::Open(name)) = action {
+                let rate = self.pressures[self.valves.id(&name).unwrap()];
+                open.push((name, rate));
+                open.sort();
+            }
+        }
+        Plan { minutes }
+    }
+
+    fn collect_pressures_by_opened(
+        &self,
+        pos: usize,
+        remaining_time: u32,
+        opened: BitSet,
+        released: u32,
+        best: &mut HashMap<BitSet, u32>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = max(*entry, released);
+        for (next, time_after_opening) in self.next_valves(pos, remaining_time, opened) {
+            self.collect_pressures_by_opened(
+                next,
+                time_after_opening,
+                opened.with(next),
+                released + time_after_opening * self.pressures[next],
+                best,
+            );
+        }
+    }
+
+    /// The highest pressure that can be released in `time` for every set of
+    /// valves that can be opened in that time, opening exactly those valves.
+    fn get_pressures_by_opened(&self, start: &str, time: u32) -> HashMap<BitSet, u32> {
+        let start = self.valves.id(start).expect("Unknown start valve");
+        let mut best = HashMap::new();
+        self.collect_pressures_by_opened(start, time, BitSet::new(), 0, &mut best);
+        best
+    }
+
+    /// The highest pressure two agents working at the same time can release,
+    /// each opening a different set of valves.
+    fn get_maximum_released_pressure_together(&self, start: &str, time: u32) -> u32 {
+        let mut by_opened: Vec<(BitSet, u32)> = self
+            .get_pressures_by_opened(start, time)
+            .into_iter()
+            .collect();
+        by_opened.sort_unstable_by_key(|(_, pressure)| std::cmp::Reverse(*pressure));
+        let mut highest_pressure = 0;
+        for (idx, (mine, my_pressure)) in by_opened.iter().enumerate() {
+            // The rest is sorted, so no other pairing with mine can do better
+            if my_pressure * 2 < highest_pressure {
+                break;
+            }
+            if let Some((_, other_pressure)) = by_opened[idx..]
+                .iter()
+                .find(|(theirs, _)| mine.is_disjoint(theirs))
+            {
+                highest_pressure = max(highest_pressure, my_pressure + other_pressure);
+            }
+        }
+        highest_pressure
+    }
+}
+
+/// The valves and tunnels of the scan, with the valves worth opening
+/// prepared for the search.
+pub struct Volcano {
+    tunnels: Graph,
+    calculator: MaxPressureCalculator,
+    /// The result of the search for part 1, kept for the plan behind it.
+    best_order: OnceCell<(u32, Vec<usize>)>,
+}
+
+impl Volcano {
+    /// The highest pressure to release alone in 30 minutes and the order in
+    /// which to open valves for it, searched for on first use.
+    fn best_order(&self) -> &(u32, Vec<usize>) {
+        self.best_order
+            .get_or_init(|| self.calculator.get_best_order("AA", 30))
+    }
+}
+
+fn parse_volcano(input: &str) -> Result<Volcano, ParseError> {
+    let parsed = parse_input(input)?;
+    if !parsed.iter().any(|e| e.from == "AA") {
+        return Err(ParseError::new(1, "", "a valve AA to start from"));
+    }
+    let (valves, pressures) = valve_graph(&parsed, "AA");
+    if valves.len() > BitSet::<u64>::CAPACITY {
+        return Err(ParseError::new(
+            1,
+            &format!("{} valves with a flow rate", valves.len()),
+            &format!(
+                "at most {} valves with a flow rate",
+                BitSet::<u64>::CAPACITY
+            ),
+        ));
+    }
+    Ok(Volcano {
+        tunnels: tunnel_graph(&parsed),
+        calculator: MaxPressureCalculator::new(valves, pressures),
+        best_order: OnceCell::new(),
+    })
+}
+
+pub fn solution1(input: &str) -> u32 {
+    let volcano = parse_volcano(input).expect("Failed to parse valves");
+    volcano.best_order().0
+}
+
+/// The plan behind the answer of part 1, from the same search.
+pub fn plan1(volcano: &Volcano) -> Plan {
+    let (_, order) = volcano.best_order();
+    volcano
+        .calculator
+        .get_plan(&volcano.tunnels, "AA", 30, order)
+}
+
+pub fn solution2(input: &str) -> u32 {
+    let volcano = parse_volcano(input).expect("Failed to parse valves");
+    volcano
+        .calculator
+        .get_maximum_released_pressure_together("AA", 26)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Volcano;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_volcano(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        input.best_order().0
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        input
+            .calculator
+            .get_maximum_released_pressure_together("AA", 26)
+    }
+
+    /// The minute-by-minute plan behind part 1.
+    fn explain(input: &Self::Input, _params: &Self::Params, part: u32) -> Option<String> {
+        (part == 1).then(|| plan1(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parse_edge, parse_input, parse_volcano, plan1, solution1, solution2, valve_graph, Action,
+        Day16, Edge, MaxPressureCalculator,
+    };
+    use std::collections::HashSet;
+    use utils::{BitSet, Solution};
+
+    const TEST_STRING: &str = r"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn test_parse_edge() {
+        assert_eq!(
+            parse_edge("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB")
+                .unwrap()
+                .1,
+            HashSet::from([
+                Edge::new("AA", 0, "DD"),
+                Edge::new("AA", 0, "II"),
+                Edge::new("AA", 0, "BB")
+            ])
+        );
+        assert_eq!(
+            parse_edge("Valve HH has flow rate=0; tunnel leads to valves GG")
+                .unwrap()
+                .1,
+            HashSet::from([Edge::new("HH", 0, "GG")])
+        );
+        assert_eq!(
+            parse_edge("Valve GG has flow rate=0; tunnels lead to valves FF, HH")
+                .unwrap()
+                .1,
+            HashSet::from([Edge::new("GG", 0, "FF"), Edge::new("GG", 0, "HH")])
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let result = parse_input(TEST_STRING).unwrap();
+        assert_eq!(result.len(), 20);
+        assert_eq!(
+            result,
+            HashSet::from([
+                Edge::new("AA", 0, "DD"),
+                Edge::new("AA", 0, "II"),
+                Edge::new("AA", 0, "BB"),
+                Edge::new("BB", 13, "CC"),
+                Edge::new("BB", 13, "AA"),
+                Edge::new("CC", 2, "DD"),
+                Edge::new("CC", 2, "BB"),
+                Edge::new("DD", 20, "CC"),
+                Edge::new("DD", 20, "AA"),
+                Edge::new("DD", 20, "EE"),
+                Edge::new("EE", 3, "FF"),
+                Edge::new("EE", 3, "DD"),
+                Edge::new("FF", 0, "EE"),
+                Edge::new("FF", 0, "GG"),
+                Edge::new("GG", 0, "FF"),
+                Edge::new("GG", 0, "HH"),
+                Edge::new("HH", 22, "GG"),
+                Edge::new("II", 0, "AA"),
+                Edge::new("II", 0, "JJ"),
+                Edge::new("JJ", 21, "II")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_valve_graph() {
+        let parsed = parse_input(TEST_STRING).unwrap();
+        let (valves, pressures) = valve_graph(&parsed, "AA");
+        assert_eq!(valves.len(), 7);
+        assert_eq!(pressures.iter().sum::<u32>(), 81);
+        let aa = valves.id("AA").unwrap();
+        let hh = valves.id("HH").unwrap();
+        assert_eq!(pressures[hh], 22);
+        assert!(valves.edges(aa).contains(&(hh, 5)));
+        assert_eq!(valves.id("GG"), None);
+    }
+
+    #[test]
+    fn test_dynamic_programming() {
+        let parsed = parse_input(TEST_STRING).unwrap();
+        let (valves, pressures) = valve_graph(&parsed, "AA");
+        let calculator = MaxPressureCalculator::new(valves, pressures);
+        assert_eq!(calculator.get_best_order("AA", 30).0, 1651);
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(TEST_STRING), 1651);
+    }
+
+    #[test]
+    fn test_pressures_by_opened() {
+        let parsed = parse_input(TEST_STRING).unwrap();
+        let (valves, pressures) = valve_graph(&parsed, "AA");
+        let calculator = MaxPressureCalculator::new(valves, pressures);
+        let by_opened = calculator.get_pressures_by_opened("AA", 30);
+        assert_eq!(by_opened.values().max(), Some(&1651));
+        assert_eq!(by_opened[&BitSet::new()], 0);
+        let jj = calculator.valves.id("JJ").unwrap();
+        assert_eq!(by_opened[&BitSet::new().with(jj)], 21 * 27);
+    }
+
+    #[test]
+    fn test_plan() {
+        let volcano = parse_volcano(TEST_STRING).unwrap();
+        assert_eq!(Day16::part1(&volcano, &()), 1651);
+        let order = volcano
+            .best_order
+            .get()
+            .expect("Part 1 keeps its search")
+            .1
+            .clone();
+        let plan = plan1(&volcano);
+        assert_eq!(volcano.best_order().1, order);
+        assert_eq!(plan.released(), 1651);
+        assert_eq!(plan.minutes.len(), 30);
+        assert_eq!(plan.minutes[1].action, Some(Action::Open("DD".to_string())));
+        let log = plan.to_string();
+        assert!(log.starts_with(
+            "== Minute 1 ==
+No valves are open.
+You move to valve DD.
+
+== Minute 2 ==
+No valves are open.
+You open valve DD.
+
+== Minute 3 ==
+Valve DD is open, releasing 20 pressure.
+"
+        ));
+        assert!(log.contains(
+            "== Minute 6 ==
+Valves BB and DD are open, releasing 33 pressure.
+"
+        ));
+        assert!(log.ends_with(
+            "== Minute 30 ==
+Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+"
+        ));
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(TEST_STRING), 1707);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input =
+            "Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=x\n";
+        let error = Day16::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(24)));
+        assert!(Day16::parse("Valve BB has flow rate=1; tunnel leads to valve CC\n").is_err());
+    }
+}
@@ -0,0 +1,513 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use utils::{blocks_with, string_lines, Direction, Input, ParseError, Point};
+
+/// A rock as one bitmask per row, bottom row first. Column `c`, counted from
+/// the left edge of the rock, is bit `c`.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Rock {
+    rows: Vec<u8>,
+    width: usize,
+}
+
+impl Rock {
+    pub fn new(rows: Vec<u8>) -> Self {
+        let width = rows
+            .iter()
+            .map(|row| (u8::BITS - row.leading_zeros()) as usize)
+            .max()
+            .unwrap_or(0);
+        Self { rows, width }
+    }
+
+    /// Parses a rock drawn with `#` for rock and `.` for air, top row first.
+    /// Empty rows and columns around the rock are dropped, so its bottom left
+    /// corner is where it appears in the chamber.
+    pub fn from_ascii(lines: &[String]) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+        for (idx, line) in lines.iter().enumerate() {
+            let mut row = 0u8;
+            for (column, c) in line.chars().enumerate() {
+                match c {
+                    '#' if column >= u8::BITS as usize => {
+                        return Err(ParseError::new(
+                            column + 1,
+                            &c.to_string(),
+                            "a rock at most 8 units wide",
+                        )
+                        .on_line(idx + 1))
+                    }
+                    '#' => row |= 1 << column,
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError::new(column + 1, &c.to_string(), "'#' or '.'")
+                            .on_line(idx + 1))
+                    }
+                }
+            }
+            rows.push(row);
+        }
+        let left = rows
+            .iter()
+            .map(|row| row.trailing_zeros())
+            .min()
+            .unwrap_or(u8::BITS);
+        if left == u8::BITS {
+            return Err(ParseError::new(1, "", "a rock with at least one '#'"));
+        }
+        let top = rows.iter().position(|row| *row != 0).unwrap();
+        let bottom = rows.iter().rposition(|row| *row != 0).unwrap();
+        Ok(Self::new(
+            rows[top..=bottom]
+                .iter()
+                .rev()
+                .map(|row| row >> left)
+                .collect(),
+        ))
+    }
+
+    pub fn rows(&self) -> &[u8] {
+        &self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The points the rock covers, relative to its bottom left corner.
+    pub fn points(&self) -> Vec<Point> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                (0..self.width)
+                    .filter(move |x| row & (1 << x) != 0)
+                    .map(move |x| Point::new(x as isize, y as isize))
+            })
+            .collect()
+    }
+
+    /// The bottom left and top right corners of the rock.
+    pub fn bounds(&self) -> (Point, Point) {
+        (
+            Point::new(0, 0),
+            Point::new(self.width as isize - 1, self.rows.len() as isize - 1),
+        )
+    }
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows.iter().rev() {
+            let cells: String = (0..self.width)
+                .map(|column| if row & (1 << column) != 0 { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{}", cells)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses rocks drawn as for `Rock::from_ascii`, separated by empty lines.
+/// Rocks wider than `max_width`, the room a chamber leaves between where
+/// rocks appear and its right wall, are rejected.
+pub fn parse_rocks(input: &str, max_width: usize) -> Result<Vec<Rock>, ParseError> {
+    blocks_with(string_lines(input), |lines| {
+        let rock = Rock::from_ascii(lines)?;
+        if rock.width > max_width {
+            return Err(ParseError::new(
+                1,
+                &format!("a rock {} units wide", rock.width),
+                &format!("a rock at most {} units wide", max_width),
+            ));
+        }
+        Ok(rock)
+    })
+    .collect()
+}
+
+pub fn load_rocks(filename: &str, max_width: usize) -> Result<Vec<Rock>, ParseError> {
+    parse_rocks(&Input::path(filename).read_to_string()?, max_width)
+}
+
+/// Why a chamber cannot be set up with the given rocks and jets.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ChamberError {
+    /// The chamber is wider than the 8 columns a row holds.
+    TooWide(usize),
+    NoRocks,
+    NoJets,
+    /// A jet pushes some other way than east or west.
+    SidewaysJet(Direction),
+    /// Rock `rock` sticks out of the chamber where it appears.
+    RockDoesNotFit {
+        rock: usize,
+        rock_width: usize,
+        spawn_x: usize,
+        width: usize,
+    },
+}
+
+impl Display for ChamberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChamberError::TooWide(width) => {
+                write!(f, "A chamber is at most 8 units wide, not {}", width)
+            }
+            ChamberError::NoRocks => write!(f, "A chamber needs at least one rock"),
+            ChamberError::NoJets => write!(f, "A chamber needs at least one jet"),
+            ChamberError::SidewaysJet(direction) => {
+                write!(f, "Jets push rocks east or west, not {:?}", direction)
+            }
+            ChamberError::RockDoesNotFit {
+                rock,
+                rock_width,
+                spawn_x,
+                width,
+            } => write!(
+                f,
+                "Rock {} is {} units wide and does not fit {} units from the wall of a \
+                 chamber {} units wide",
+                rock, rock_width, spawn_x, width
+            ),
+        }
+    }
+}
+
+impl Error for ChamberError {}
+
+/// Whether `rock`, with its bottom left corner at `(x, y)`, overlaps the
+/// rocks in `rows` or sticks out of a chamber `width` wide.
+fn collides(rows: &[u8], width: usize, rock: &Rock, x: usize, y: usize) -> bool {
+    x + rock.width > width
+        || rock
+            .rows
+            .iter()
+            .enumerate()
+            .any(|(idx, mask)| rows.get(y + idx).is_some_and(|row| row & (mask << x) != 0))
+}
+
+/// A chamber rocks fall into, one row per `u8` from the floor up, while jets
+/// of hot gas push them sideways. Rocks fall in the order they were given,
+/// as do the jets, both starting over when they run out.
+#[derive(Clone, Debug)]
+pub struct Chamber {
+    width: usize,
+    rows: Vec<u8>,
+    rocks: Vec<Rock>,
+    jets: Vec<Direction>,
+    spawn_x: usize,
+    spawn_gap: usize,
+    next_rock: usize,
+    next_jet: usize,
+    dropped: usize,
+}
+
+impl Chamber {
+    /// How far from the left wall rocks appear unless told otherwise.
+    pub const DEFAULT_SPAWN_X: usize = 2;
+    /// How many empty rows are below a rock when it appears unless told
+    /// otherwise.
+    pub const DEFAULT_SPAWN_GAP: usize = 3;
+
+    /// A chamber where each rock appears two units from the left wall with
+    /// three empty rows below it, panicking if that cannot be set up.
+    pub fn new(width: usize, rocks: Vec<Rock>, jets: Vec<Direction>) -> Self {
+        Self::try_new(
+            width,
+            rocks,
+            jets,
+            Self::DEFAULT_SPAWN_X,
+            Self::DEFAULT_SPAWN_GAP,
+        )
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// A chamber where each rock appears `spawn_x` units from the left wall
+    /// with `spawn_gap` empty rows below it. Fails if the chamber is too
+    /// wide, if there are no rocks or jets, if a jet does not push east or
+    /// west, or if a rock does not fit where it appears.
+    pub fn try_new(
+        width: usize,
+        rocks: Vec<Rock>,
+        jets: Vec<Direction>,
+        spawn_x: usize,
+        spawn_gap: usize,
+    ) -> Result<Self, ChamberError> {
+        if width > u8::BITS as usize {
+            return Err(ChamberError::TooWide(width));
+        }
+        if rocks.is_empty() {
+            return Err(ChamberError::NoRocks);
+        }
+        if jets.is_empty() {
+            return Err(ChamberError::NoJets);
+        }
+        if let Some(jet) = jets
+            .iter()
+            .find(|d| !matches!(d, Direction::East | Direction::West))
+        {
+            return Err(ChamberError::SidewaysJet(*jet));
+        }
+        let chamber = Self {
+            width,
+            rows: Vec::new(),
+            rocks,
+            jets,
+            spawn_x,
+            spawn_gap,
+            next_rock: 0,
+            next_jet: 0,
+            dropped: 0,
+        };
+        chamber.check_spawn()?;
+        Ok(chamber)
+    }
+
+    /// Makes rocks appear `x` units from the left wall, with `gap` empty
+    /// rows between them and the highest rock or the floor, panicking if a
+    /// rock does not fit there.
+    pub fn with_spawn(mut self, x: usize, gap: usize) -> Self {
+        self.spawn_x = x;
+        self.spawn_gap = gap;
+        self.check_spawn().unwrap_or_else(|e| panic!("{}", e));
+        self
+    }
+
+    fn check_spawn(&self) -> Result<(), ChamberError> {
+        match self
+            .rocks
+            .iter()
+            .position(|rock| self.spawn_x + rock.width > self.width)
+        {
+            Some(rock) => Err(ChamberError::RockDoesNotFit {
+                rock,
+                rock_width: self.rocks[rock].width,
+                spawn_x: self.spawn_x,
+                width: self.width,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The index of the rock that falls next.
+    pub fn next_rock(&self) -> usize {
+        self.next_rock
+    }
+
+    /// The index of the jet that pushes next.
+    pub fn next_jet(&self) -> usize {
+        self.next_jet
+    }
+
+    /// The rows at the top of the tower that still matter to where rocks
+    /// come to rest, top row last. These are the rows a falling rock can
+    /// reach by moving down, left and right through empty cells, and the
+    /// row below the deepest of them that it would land on. Two chambers
+    /// with the same surface, next rock and next jet grow the same way.
+    pub fn surface(&self) -> &[u8] {
+        let full = ((1u16 << self.width) - 1) as u8;
+        let mut reachable = full;
+        for (idx, row) in self.rows.iter().enumerate().rev() {
+            let empty = !row & full;
+            reachable &= empty;
+            loop {
+                let spread = (reachable | reachable << 1 | reachable >> 1) & empty;
+                if spread == reachable {
+                    break;
+                }
+                reachable = spread;
+            }
+            if reachable == 0 {
+                return &self.rows[idx..];
+            }
+        }
+        &self.rows
+    }
+
+    fn push(&mut self) -> Direction {
+        let direction = self.jets[self.next_jet];
+        self.next_jet = (self.next_jet + 1) % self.jets.len();
+        direction
+    }
+
+    /// Lets the next rock fall until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let rock_idx = self.next_rock;
+        self.next_rock = (self.next_rock + 1) % self.rocks.len();
+        let mut x = self.spawn_x;
+        let mut y = self.rows.len() + self.spawn_gap;
+        loop {
+            let direction = self.push();
+            let rock = &self.rocks[rock_idx];
+            if let Some(pushed) = x.checked_add_signed(direction.offset().x) {
+                if !collides(&self.rows, self.width, rock, pushed, y) {
+                    x = pushed;
+                }
+            }
+            if y == 0 || collides(&self.rows, self.width, rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        let rock = &self.rocks[rock_idx];
+        if self.rows.len() < y + rock.height() {
+            self.rows.resize(y + rock.height(), 0);
+        }
+        for (idx, mask) in rock.rows.iter().enumerate() {
+            self.rows[y + idx] |= mask << x;
+        }
+        self.dropped += 1;
+    }
+
+    /// The tower as drawn in the puzzle, top row first.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows.iter().rev() {
+            let cells: String = (0..self.width)
+                .map(|column| if row & (1 << column) != 0 { '#' } else { '.' })
+                .collect();
+            writeln!(f, "|{}|", cells)?;
+        }
+        writeln!(f, "+{}+", "-".repeat(self.width))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::chamber::{parse_rocks, Chamber, ChamberError, Rock};
+    use utils::{string_lines, Direction, ParseError, Point};
+
+    fn cross() -> Rock {
+        Rock::new(vec![0b010, 0b111, 0b010])
+    }
+
+    fn rock(art: &str) -> Result<Rock, ParseError> {
+        Rock::from_ascii(&string_lines(art).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_rock() {
+        let ell = Rock::new(vec![0b111, 0b100, 0b100]);
+        assert_eq!((ell.width(), ell.height()), (3, 3));
+        assert_eq!(Rock::new(vec![1, 1, 1, 1]).width(), 1);
+        assert_eq!(ell.bounds(), (Point::new(0, 0), Point::new(2, 2)));
+        assert_eq!(
+            Rock::new(vec![0b11, 0b01]).points(),
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        assert_eq!(
+            rock("..#\n..#\n###"),
+            Ok(Rock::new(vec![0b111, 0b100, 0b100]))
+        );
+        assert_eq!(rock(".#.\n###\n.#."), Ok(cross()));
+        assert_eq!(rock("....\n..##\n..#.\n"), Ok(Rock::new(vec![0b01, 0b11])));
+        assert_eq!(cross().to_string(), ".#.\n###\n.#.\n");
+        assert_eq!(
+            rock("#.\n#x"),
+            Err(ParseError::new(2, "x", "'#' or '.'").on_line(2))
+        );
+        assert!(rock("..\n..").is_err());
+        assert!(rock(".........#").is_err());
+    }
+
+    #[test]
+    fn test_parse_rocks() {
+        let rocks = parse_rocks("####\n\n#\n#\n\n##\n##\n", 5).unwrap();
+        assert_eq!(rocks.len(), 3);
+        assert_eq!(rocks[1], Rock::new(vec![1, 1]));
+        assert_eq!(
+            parse_rocks("##\n\n.#\n#?", 5),
+            Err(ParseError::new(2, "?", "'#' or '.'").on_line(4))
+        );
+        assert_eq!(
+            parse_rocks("##\n\n......\n######\n", 5),
+            Err(
+                ParseError::new(1, "a rock 6 units wide", "a rock at most 5 units wide").on_line(3)
+            )
+        );
+    }
+
+    #[test]
+    fn test_drop_rock() {
+        let mut chamber = Chamber::new(5, vec![cross()], vec![Direction::East]);
+        chamber.drop_rock();
+        assert_eq!(chamber.render(), "|...#.|\n|..###|\n|...#.|\n+-----+\n");
+        assert_eq!(chamber.surface(), &[0b01000, 0b11100, 0b01000]);
+        chamber.drop_rock();
+        assert_eq!(chamber.height(), 6);
+        assert_eq!(chamber.dropped(), 2);
+        assert_eq!((chamber.next_rock(), chamber.next_jet()), (0, 0));
+    }
+
+    #[test]
+    fn test_spawn() {
+        let mut chamber =
+            Chamber::new(3, vec![Rock::new(vec![1])], vec![Direction::West]).with_spawn(2, 0);
+        chamber.drop_rock();
+        assert_eq!(chamber.render(), "|.#.|\n+---+\n");
+    }
+
+    #[test]
+    fn test_surface() {
+        let mut chamber =
+            Chamber::try_new(3, vec![Rock::new(vec![0b111])], vec![Direction::West], 0, 0).unwrap();
+        assert!(chamber.surface().is_empty());
+        chamber.drop_rock();
+        chamber.drop_rock();
+        assert_eq!(chamber.height(), 2);
+        assert_eq!(chamber.surface(), &[0b111]);
+    }
+
+    #[test]
+    fn test_rock_does_not_fit() {
+        let wide = Rock::new(vec![0b111111]);
+        let jets = vec![Direction::East];
+        assert_eq!(
+            Chamber::try_new(7, vec![cross(), wide.clone()], jets.clone(), 2, 3).err(),
+            Some(ChamberError::RockDoesNotFit {
+                rock: 1,
+                rock_width: 6,
+                spawn_x: 2,
+                width: 7
+            })
+        );
+        assert!(Chamber::try_new(7, vec![wide], jets.clone(), 1, 3).is_ok());
+        assert_eq!(
+            Chamber::try_new(9, vec![cross()], jets, 2, 3).err(),
+            Some(ChamberError::TooWide(9))
+        );
+        assert_eq!(
+            Chamber::try_new(5, vec![cross()], vec![Direction::North], 2, 3).err(),
+            Some(ChamberError::SidewaysJet(Direction::North))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_spawn_outside() {
+        Chamber::new(3, vec![cross()], vec![Direction::East]).with_spawn(9, 0);
+    }
+}
@@ -0,0 +1,192 @@
+extern crate nom;
+
+mod chamber;
+
+pub use chamber::{load_rocks, parse_rocks, Chamber, ChamberError, Rock};
+use utils::cycle::find_cycle_within;
+use utils::{string_lines, Direction, ParseError, Solution};
+
+/// The five rocks of the puzzle, in the order they fall.
+const STANDARD_ROCKS: &str = "####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##
+";
+
+/// How many units wide the puzzle's chamber is.
+const CHAMBER_WIDTH: usize = 7;
+
+fn standard_rocks() -> Vec<Rock> {
+    parse_rocks(STANDARD_ROCKS, CHAMBER_WIDTH - Chamber::DEFAULT_SPAWN_X)
+        .expect("Invalid standard rocks")
+}
+
+fn parse_jet_pattern(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut result = Vec::new();
+    for (idx, line) in string_lines(input).enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            result.push(match c {
+                '>' => Direction::East,
+                '<' => Direction::West,
+                _ => {
+                    return Err(
+                        ParseError::new(col + 1, &c.to_string(), "'>' or '<'").on_line(idx + 1)
+                    )
+                }
+            });
+        }
+    }
+    if result.is_empty() {
+        return Err(ParseError::new(1, "", "at least one jet").on_line(1));
+    }
+    Ok(result)
+}
+
+fn chamber(jets: &[Direction]) -> Chamber {
+    Chamber::new(CHAMBER_WIDTH, standard_rocks(), jets.to_vec())
+}
+
+fn tower_height(jets: &[Direction], rounds: u32) -> u32 {
+    let mut chamber = chamber(jets);
+    for _ in 1..=rounds {
+        chamber.drop_rock();
+    }
+    chamber.height().try_into().unwrap()
+}
+
+/// Drops rocks until the chamber gets into a state it was in before, then
+/// extrapolates the height over the repetitions of the rocks in between.
+/// A tower that never repeats within `rounds` rocks has simply been built.
+fn extrapolated_tower_height(jets: &[Direction], rounds: u64) -> u64 {
+    let mut chamber = chamber(jets);
+    // The next rock, the next jet and the top of the tower rocks can reach.
+    let state = |chamber: &Chamber| {
+        (
+            (
+                chamber.next_rock(),
+                chamber.next_jet(),
+                chamber.surface().to_vec(),
+            ),
+            chamber.height(),
+        )
+    };
+    match find_cycle_within(
+        state(&chamber),
+        |_| {
+            chamber.drop_rock();
+            state(&chamber)
+        },
+        |(key, _)| key.clone(),
+        rounds.try_into().unwrap_or(usize::MAX),
+    ) {
+        Some(cycle) => cycle.extrapolate(rounds, |(_, height)| *height as i64) as u64,
+        None => chamber.height() as u64,
+    }
+}
+
+pub fn solution1(input: &str, rounds: u32) -> u32 {
+    tower_height(
+        &parse_jet_pattern(input).expect("Invalid jet pattern"),
+        rounds,
+    )
+}
+
+pub fn solution2(input: &str, rounds: u64) -> u64 {
+    extrapolated_tower_height(
+        &parse_jet_pattern(input).expect("Invalid jet pattern"),
+        rounds,
+    )
+}
+
+pub struct Params {
+    pub rounds: u32,
+    pub rounds2: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            rounds: 2022,
+            rounds2: 1_000_000_000_000,
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+    type Params = Params;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_jet_pattern(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer1 {
+        tower_height(input, params.rounds)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer2 {
+        extrapolated_tower_height(input, params.rounds2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{chamber, parse_jet_pattern, solution1, solution2, Day17};
+    use utils::Solution;
+
+    const TEST_STRING: &str = r">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn test_chamber() {
+        let mut chamber = chamber(&parse_jet_pattern(TEST_STRING).unwrap());
+        for _ in 0..3 {
+            chamber.drop_rock();
+        }
+        assert_eq!(
+            chamber.render(),
+            "|..#....|
+|..#....|
+|####...|
+|..###..|
+|...#...|
+|..####.|
++-------+
+"
+        );
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(TEST_STRING, 2022), 3068);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(TEST_STRING, 2022), 3068);
+        assert_eq!(solution2(TEST_STRING, 1_000_000_000_000), 1514285714288);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day17::parse(">><x<\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(4)));
+        assert!(Day17::parse("").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+use utils::{parse_lines, string_lines, ParseError, Solution};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum RPS {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for RPS {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(RPS::Rock),
+            "B" | "Y" => Ok(RPS::Paper),
+            "C" | "Z" => Ok(RPS::Scissors),
+            _ => Err(ParseError::new(
+                1,
+                s,
+                "one of 'A', 'B', 'C', 'X', 'Y' or 'Z'",
+            )),
+        }
+    }
+}
+
+impl PartialOrd for RPS {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(match (self, other) {
+            (RPS::Paper, RPS::Rock) | (RPS::Scissors, RPS::Paper) | (RPS::Rock, RPS::Scissors) => {
+                Ordering::Greater
+            }
+            (RPS::Rock, RPS::Paper) | (RPS::Paper, RPS::Scissors) | (RPS::Scissors, RPS::Rock) => {
+                Ordering::Less
+            }
+            (RPS::Rock, RPS::Rock) | (RPS::Paper, RPS::Paper) | (RPS::Scissors, RPS::Scissors) => {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum DesiredOutcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+impl FromStr for DesiredOutcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(DesiredOutcome::Lose),
+            "Y" => Ok(DesiredOutcome::Draw),
+            "Z" => Ok(DesiredOutcome::Win),
+            _ => Err(ParseError::new(1, s, "one of 'X', 'Y' or 'Z'")),
+        }
+    }
+}
+
+/// One line of the strategy guide. The second column is the shape to play in
+/// part 1 and the outcome to aim for in part 2, so it is kept both ways.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Round {
+    theirs: RPS,
+    mine: RPS,
+    outcome: DesiredOutcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (theirs, second) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(1, s, "two columns separated by a space"))?;
+        let column = theirs.len() + 2;
+        let at_column = |e: ParseError| ParseError::new(column, &e.text, &e.expected);
+        Ok(Round {
+            theirs: theirs.parse()?,
+            mine: second.parse().map_err(at_column)?,
+            outcome: second.parse().map_err(at_column)?,
+        })
+    }
+}
+
+fn wins_against(r: RPS) -> RPS {
+    match r {
+        RPS::Rock => RPS::Paper,
+        RPS::Paper => RPS::Scissors,
+        RPS::Scissors => RPS::Rock,
+    }
+}
+
+fn loses_against(r: RPS) -> RPS {
+    match r {
+        RPS::Paper => RPS::Rock,
+        RPS::Rock => RPS::Scissors,
+        RPS::Scissors => RPS::Paper,
+    }
+}
+
+fn shape_score(r: RPS) -> u32 {
+    match r {
+        RPS::Rock => 1,
+        RPS::Paper => 2,
+        RPS::Scissors => 3,
+    }
+}
+
+fn score_rps(theirs: RPS, mine: RPS) -> u32 {
+    (match (theirs, mine) {
+        (x, y) if x == y => 3,
+        (x, y) if x > y => 0,
+        (x, y) if x < y => 6,
+        (x, y) => panic!("Unkown RPS battle {:?} vs {:?}", x, y),
+    }) + shape_score(mine)
+}
+
+fn score_shapes(rounds: &[Round]) -> u32 {
+    rounds.iter().map(|r| score_rps(r.theirs, r.mine)).sum()
+}
+
+fn shape_for_outcome(theirs: RPS, outcome: DesiredOutcome) -> RPS {
+    match (theirs, outcome) {
+        (x, DesiredOutcome::Draw) => x,
+        (x, DesiredOutcome::Win) => wins_against(x),
+        (x, DesiredOutcome::Lose) => loses_against(x),
+    }
+}
+
+fn score_outcomes(rounds: &[Round]) -> u32 {
+    rounds
+        .iter()
+        .map(|r| score_rps(r.theirs, shape_for_outcome(r.theirs, r.outcome)))
+        .sum()
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    score_shapes(&parse_lines(lines).expect("Failed to parse rounds"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    score_outcomes(&parse_lines(lines).expect("Failed to parse rounds"))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        score_shapes(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        score_outcomes(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{solution1, solution2, Day2};
+    use utils::{string_lines, Solution};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"
+A Y
+B X
+C Z
+",
+        )
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), 15);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day2::parse("A Y\nB W\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+    }
+}
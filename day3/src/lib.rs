@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use utils::{parse_lines, string_lines, ParseError, Solution};
+
+/// The items in one rucksack, each a letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack(Vec<char>);
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                if c.is_ascii_alphabetic() {
+                    Ok(c)
+                } else {
+                    Err(ParseError::new(idx + 1, &c.to_string(), "an item letter"))
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Rucksack)
+    }
+}
+
+fn char_priority(c: &char) -> u32 {
+    if c.is_uppercase() {
+        *c as u32 - 64 + 26
+    } else {
+        *c as u32 - 96
+    }
+}
+
+fn score_compartments_single(l: &HashSet<char>, r: &HashSet<char>) -> u32 {
+    l.intersection(r).map(char_priority).sum()
+}
+
+fn misplaced_priorities(rucksacks: &[Rucksack]) -> u32 {
+    let mut result = 0;
+    for Rucksack(items) in rucksacks {
+        let (first, second) = items.split_at(items.len() / 2);
+        let first_compartment = HashSet::from_iter(first.iter().copied());
+        let second_compartment = HashSet::from_iter(second.iter().copied());
+        result += score_compartments_single(&first_compartment, &second_compartment);
+    }
+    result
+}
+
+fn badge_priorities(rucksacks: &[Rucksack]) -> u32 {
+    let mut result = 0;
+    for group in rucksacks.chunks_exact(3) {
+        let g1: HashSet<char> = HashSet::from_iter(group[0].0.iter().copied());
+        let g2 = HashSet::from_iter(group[1].0.iter().copied());
+        let g3 = HashSet::from_iter(group[2].0.iter().copied());
+        let mut common: HashSet<_> = g1.intersection(&g2).cloned().collect();
+        common = common.intersection(&g3).cloned().collect();
+        for c in common {
+            result += char_priority(&c);
+        }
+    }
+    result
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    misplaced_priorities(&parse_lines(lines).expect("Failed to parse rucksacks"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    badge_priorities(&parse_lines(lines).expect("Failed to parse rucksacks"))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        misplaced_priorities(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        badge_priorities(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{solution1, solution2, Day3};
+    use utils::{string_lines, Solution};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+",
+        )
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), 157);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), 70);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day3::parse("vJrwpWtwJgWr\nabc1ef\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(4)));
+    }
+}
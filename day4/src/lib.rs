@@ -0,0 +1,124 @@
+use std::ops::Range;
+use std::str::FromStr;
+use utils::{parse_lines, string_lines, try_num_between, ParseError, Solution};
+
+/// The section ranges of two elves, with exclusive ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair(Range<u32>, Range<u32>);
+
+/// Parses a range like `2-4` that starts at 0-based `offset` of its line.
+fn parse_range(s: &str, offset: usize) -> Result<Range<u32>, ParseError> {
+    let bounds = try_num_between(s, None, Some("-"))
+        .and_then(|start| Ok((start, try_num_between::<u32>(s, Some("-"), None)?)));
+    match bounds {
+        Ok((start, end)) => Ok(start..end + 1),
+        Err(mut e) => {
+            e.column = e.column.map(|c| c + offset);
+            Err(e)
+        }
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(1, s, "two ranges separated by ','"))?;
+        Ok(Pair(
+            parse_range(first, 0)?,
+            parse_range(second, first.len() + 1)?,
+        ))
+    }
+}
+
+// Whether r1 is completely contained in r2 or the reverse
+fn ranges_contained(r1: Range<u32>, r2: Range<u32>) -> bool {
+    (r2.contains(&r1.start) && r2.contains(&(r1.end - 1)))
+        || (r1.contains(&r2.start) && r1.contains(&(r2.end - 1)))
+}
+
+fn ranges_overlap(r1: Range<u32>, r2: Range<u32>) -> bool {
+    r2.contains(&r1.start)
+        || r2.contains(&(r1.end - 1))
+        || r1.contains(&r2.start)
+        || r1.contains(&(r2.end - 1))
+}
+
+fn count_pairs(pairs: &[Pair], pred: fn(Range<u32>, Range<u32>) -> bool) -> u32 {
+    pairs
+        .iter()
+        .filter(|Pair(r1, r2)| pred(r1.clone(), r2.clone()))
+        .count() as u32
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    count_pairs(
+        &parse_lines(lines).expect("Failed to parse pairs"),
+        ranges_contained,
+    )
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    count_pairs(
+        &parse_lines(lines).expect("Failed to parse pairs"),
+        ranges_overlap,
+    )
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        count_pairs(input, ranges_contained)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        count_pairs(input, ranges_overlap)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{solution1, solution2, Day4};
+    use utils::{string_lines, Solution};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+",
+        )
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), 2);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day4::parse("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(7)));
+    }
+}
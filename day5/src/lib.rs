@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use utils::{string_lines, try_num_between, Blocks, ParseError, Solution};
+
+type BoxConfig = HashMap<u32, Vec<char>>;
+
+fn apply_order(cfg: &mut BoxConfig, o: Order, do_rev: bool) {
+    let from_vec = cfg.get_mut(&o.from_col).expect("Invalid from column");
+    let mut drain: Vec<char> = from_vec.drain(0..(o.count as usize)).collect();
+    if do_rev {
+        drain.reverse();
+    }
+    let to_vec = cfg.get_mut(&o.to_col).expect("Invalid to column");
+    for c in drain {
+        to_vec.insert(0, c);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Order {
+    count: u32,
+    from_col: u32,
+    to_col: u32,
+}
+
+impl Order {
+    fn new(count: u32, from_col: u32, to_col: u32) -> Self {
+        Order {
+            count,
+            from_col,
+            to_col,
+        }
+    }
+}
+
+/// The starting stacks and the orders the crane carries out on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    stacks: BoxConfig,
+    orders: Vec<Order>,
+}
+
+fn get_solution_code(b: &BoxConfig) -> String {
+    let mut columns: Vec<u32> = b.keys().cloned().collect();
+    let mut result = String::new();
+    columns.sort();
+    for column in columns {
+        result.push(*b[&column].first().unwrap());
+    }
+    result
+}
+
+fn parse_box_config(lines: Vec<String>) -> BoxConfig {
+    let mut result: BoxConfig = HashMap::new();
+    for line in lines {
+        for (idx, char) in line.chars().enumerate() {
+            if char.is_uppercase() && char.is_alphabetic() {
+                let col: u32 = ((idx as u32 - 1) / 4) + 1;
+                match result.get_mut(&col) {
+                    Some(current_col) => current_col.push(char),
+                    None => {
+                        result.insert(col, vec![char]);
+                    }
+                };
+            }
+        }
+    }
+    result
+}
+
+/// The stack number between `left` and `right`, which must be one of `stacks`.
+fn parse_stack(
+    line: &str,
+    left: &str,
+    right: Option<&str>,
+    stacks: &BoxConfig,
+) -> Result<u32, ParseError> {
+    let col = try_num_between(line, Some(left), right)?;
+    if stacks.contains_key(&col) {
+        Ok(col)
+    } else {
+        let column = line.find(left).map_or(1, |idx| idx + left.len() + 1);
+        Err(ParseError::new(
+            column,
+            &col.to_string(),
+            "the number of a stack",
+        ))
+    }
+}
+
+/// Parses the orders, with line numbers relative to the first order.
+fn parse_orders(lines: Vec<String>, stacks: &BoxConfig) -> Result<Vec<Order>, ParseError> {
+    let mut result = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let order = try_num_between(line, Some("move "), Some(" from")).and_then(|count| {
+            let from_col = parse_stack(line, "from ", Some(" to"), stacks)?;
+            let to_col = parse_stack(line, "to ", None, stacks)?;
+            Ok(Order::new(count, from_col, to_col))
+        });
+        result.push(order.map_err(|e| e.on_line(idx + 1))?);
+    }
+    Ok(result)
+}
+
+fn parse_procedure(lines: impl Iterator<Item = String>) -> Result<Procedure, ParseError> {
+    let mut blocks = Blocks::new(lines);
+    let stacks = parse_box_config(blocks.next().unwrap_or_default());
+    let orders = match blocks.next() {
+        Some(block) => {
+            let start = blocks.start_line();
+            parse_orders(block, &stacks).map_err(|e| {
+                let line = start + e.line.unwrap_or(1) - 1;
+                e.on_line(line)
+            })?
+        }
+        None => Vec::new(),
+    };
+    Ok(Procedure { stacks, orders })
+}
+
+fn run_procedure(procedure: &Procedure, do_rev: bool) -> String {
+    let mut box_config = procedure.stacks.clone();
+    for instruction in &procedure.orders {
+        apply_order(&mut box_config, *instruction, do_rev);
+    }
+    get_solution_code(&box_config)
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> String {
+    run_procedure(
+        &parse_procedure(lines).expect("Failed to parse procedure"),
+        false,
+    )
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> String {
+    run_procedure(
+        &parse_procedure(lines).expect("Failed to parse procedure"),
+        true,
+    )
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_procedure(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        run_procedure(input, false)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        run_procedure(input, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        apply_order, get_solution_code, parse_box_config, parse_procedure, solution1, solution2,
+        Day5, Order,
+    };
+    use std::collections::HashMap;
+    use utils::{string_lines, Blocks, Solution};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2",
+        )
+    }
+
+    #[test]
+    fn test_parsing_columns() {
+        let box_config = Blocks::new(test_iter()).next().unwrap();
+        let parsed_config = parse_box_config(box_config);
+        let expected = HashMap::from([
+            (1, vec!['N', 'Z']),
+            (2, vec!['D', 'C', 'M']),
+            (3, vec!['P']),
+        ]);
+        assert_eq!(parsed_config, expected);
+    }
+
+    #[test]
+    fn test_parsing_orders() {
+        let parsed_orders = parse_procedure(test_iter()).unwrap().orders;
+        let expected = vec![
+            Order::new(1, 2, 1),
+            Order::new(3, 1, 3),
+            Order::new(2, 2, 1),
+            Order::new(1, 1, 2),
+        ];
+        assert_eq!(parsed_orders, expected);
+    }
+
+    #[test]
+    fn test_apply_order() {
+        let mut state = HashMap::from([
+            (1, vec!['N', 'Z']),
+            (2, vec!['D', 'C', 'M']),
+            (3, vec!['P']),
+        ]);
+        let order = Order::new(1, 1, 2);
+        let expected = HashMap::from([
+            (1, vec!['Z']),
+            (2, vec!['N', 'D', 'C', 'M']),
+            (3, vec!['P']),
+        ]);
+        apply_order(&mut state, order, false);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn test_solution_code() {
+        let state = HashMap::from([
+            (1, vec!['N', 'Z']),
+            (2, vec!['D', 'C', 'M']),
+            (3, vec!['P']),
+        ]);
+        assert_eq!("NDP", get_solution_code(&state));
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), "CMZ");
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 3 to 1\n";
+        let error = Day5::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(5), Some(13)));
+    }
+}
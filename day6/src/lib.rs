@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use utils::{string_lines, ParseError, Solution};
+
+struct PrefixIterator<'a> {
+    s: &'a str,
+    offset: usize,
+    len: usize,
+}
+
+impl<'a> PrefixIterator<'a> {
+    fn new(s: &'a str, len: usize) -> Self {
+        PrefixIterator { offset: 0, len, s }
+    }
+}
+
+impl<'a> Iterator for PrefixIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset + self.len <= self.s.len() {
+            let result = &self.s[self.offset..self.offset + self.len];
+            self.offset += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+fn marker_end(datastream: &str, len: usize) -> u32 {
+    for (idx, prefix) in PrefixIterator::new(datastream, len).enumerate() {
+        let charset: HashSet<char> = HashSet::from_iter(prefix.chars());
+        if charset.len() == len {
+            return (idx + len) as u32;
+        };
+    }
+    panic!("No code found!");
+}
+
+/// The datastream, which is the only line of the input.
+fn parse_datastream(lines: impl Iterator<Item = String>) -> Result<String, ParseError> {
+    let mut lines = lines.enumerate().filter(|(_, l)| !l.is_empty());
+    let (_, datastream) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, "", "a datastream").on_line(1))?;
+    match lines.next() {
+        Some((idx, extra)) => Err(ParseError::new(1, &extra, "the end of input").on_line(idx + 1)),
+        None => Ok(datastream),
+    }
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    marker_end(&parse_datastream(lines).expect("Expected single line"), 4)
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    marker_end(&parse_datastream(lines).expect("Expected single line"), 14)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_datastream(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        marker_end(input, 4)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        marker_end(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{solution1, solution2, Day6, PrefixIterator};
+    use utils::{string_lines, Solution};
+
+    fn test_iter1() -> impl Iterator<Item = String> {
+        string_lines(r"mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+    }
+
+    fn test_iter2() -> impl Iterator<Item = String> {
+        string_lines(r"bvwbjplbgvbhsrlpgdmjqwftvncz")
+    }
+
+    #[test]
+    fn test_prefix_iter() {
+        let iter = PrefixIterator::new("abc", 2);
+        let prefixes: Vec<&str> = iter.collect();
+        assert_eq!(prefixes, vec!["ab", "bc"]);
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter1()), 7);
+        assert_eq!(solution1(test_iter2()), 5);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter1()), 19);
+        assert_eq!(solution2(test_iter2()), 23);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Day6::parse("").unwrap_err().line, Some(1));
+        assert_eq!(Day6::parse("abcd\n\nefgh\n").unwrap_err().line, Some(3));
+    }
+}
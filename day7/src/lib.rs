@@ -1,20 +1,46 @@
-#![feature(iter_array_chunks)]
-#![feature(map_many_mut)]
 use std::collections::HashMap;
-use utils::{lines, num_between, str_between};
+use std::str::FromStr;
+use utils::{parse_lines, string_lines, try_num_between, ParseError, Solution};
+
+/// One line of the terminal session: a command or a line of `ls` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    CdRoot,
+    CdUp,
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(u32),
+}
+
+impl FromStr for Output {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "$ cd /" => Output::CdRoot,
+            "$ cd .." => Output::CdUp,
+            "$ ls" => Output::Ls,
+            s if s.starts_with("$ cd ") => Output::Cd(s["$ cd ".len()..].to_owned()),
+            s if s.starts_with("dir ") => Output::Dir(s["dir ".len()..].to_owned()),
+            s if s.starts_with('$') => return Err(ParseError::new(1, s, "'cd' or 'ls'")),
+            s => Output::File(try_num_between(s, None, Some(" "))?),
+        })
+    }
+}
 
 fn increase_size(sizes: &mut HashMap<String, u32>, path: String, to_increase: u32) {
     let new_size = *sizes.get(&path).unwrap_or(&0) + to_increase;
     sizes.insert(path.to_string(), new_size);
 }
 
-fn parse_directory(lines: &mut impl Iterator<Item = String>) -> HashMap<String, u32> {
+fn directory_sizes(session: &[Output]) -> HashMap<String, u32> {
     let mut result: HashMap<String, u32> = HashMap::new();
     let mut current_path: Vec<String> = Vec::new();
-    for line in lines {
+    for output in session {
         let current_path_str = current_path.join("/");
-        match line {
-            line if line == "$ cd /" => {
+        match output {
+            Output::CdRoot => {
                 // This could be ignored, only happens once on first line.
                 let subdir_size = *result.get(&current_path_str).unwrap_or(&0);
                 while let Some(_p) = current_path.pop() {
@@ -22,24 +48,22 @@ fn parse_directory(lines: &mut impl Iterator<Item = String>) -> HashMap<String,
                 }
                 increase_size(&mut result, "".to_string(), subdir_size);
             }
-            line if line == "$ cd .." => {
+            Output::CdUp => {
                 let subdir_size = result[&current_path_str];
                 current_path.pop();
                 let parent_str = current_path.join("/");
                 increase_size(&mut result, parent_str.to_owned(), subdir_size);
             }
             // CD command, not root.
-            line if line.starts_with("$ cd ") => {
-                let dirname = str_between(&line, Some("$ cd "), None);
+            Output::Cd(dirname) => {
                 current_path.push(dirname.to_owned());
                 increase_size(&mut result, current_path.join("/"), 0);
             }
             // Directory in ls output
-            line if line.starts_with("dir ") => {}
-            line if line.starts_with("$ ls") => {}
-            line => {
-                let size = num_between(&line, None, Some(" "));
-                increase_size(&mut result, current_path_str, size.try_into().unwrap());
+            Output::Dir(_) => {}
+            Output::Ls => {}
+            Output::File(size) => {
+                increase_size(&mut result, current_path_str, *size);
             }
         }
     }
@@ -49,19 +73,19 @@ fn parse_directory(lines: &mut impl Iterator<Item = String>) -> HashMap<String,
         increase_size(&mut result, path.to_owned(), last_subdir_size);
         last_subdir_size = result[&path];
     }
-    return result;
+    result
 }
 
-fn solution1(mut lines: impl Iterator<Item = String>) -> u32 {
-    parse_directory(&mut lines)
+fn small_directories(session: &[Output]) -> u32 {
+    directory_sizes(session)
         .values()
         .filter(|&v| *v <= 100000)
         .cloned()
         .sum()
 }
 
-fn solution2(mut lines: impl Iterator<Item = String>) -> u32 {
-    let directory_sizes = parse_directory(&mut lines);
+fn directory_to_delete(session: &[Output]) -> u32 {
+    let directory_sizes = directory_sizes(session);
     let total_size = directory_sizes[""];
     let need_to_free: i64 = (total_size as i64) - 40000000;
     if need_to_free <= 0 {
@@ -78,11 +102,40 @@ fn solution2(mut lines: impl Iterator<Item = String>) -> u32 {
         .expect("No valid directory to delete")
 }
 
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    small_directories(&parse_lines(lines).expect("Failed to parse terminal output"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    directory_to_delete(&parse_lines(lines).expect("Failed to parse terminal output"))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Output>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        small_directories(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        directory_to_delete(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{parse_directory, solution1, solution2};
+    use crate::{directory_sizes, solution1, solution2, Day7};
     use std::collections::HashMap;
-    use utils::string_lines;
+    use utils::{parse_lines, string_lines, Solution};
 
     fn test_iter1() -> impl Iterator<Item = String> {
         string_lines(
@@ -139,7 +192,7 @@ $ ls
     #[test]
     fn test_sizes() {
         assert_eq!(
-            parse_directory(&mut test_iter1()),
+            directory_sizes(&parse_lines(test_iter1()).unwrap()),
             HashMap::from([
                 (String::from("a/e"), 584),
                 (String::from("a"), 94853),
@@ -152,7 +205,7 @@ $ ls
     #[test]
     fn test_sizes2() {
         assert_eq!(
-            parse_directory(&mut test_iter2()),
+            directory_sizes(&parse_lines(test_iter2()).unwrap()),
             HashMap::from([
                 (String::from("a"), 3),
                 (String::from("a/a"), 2),
@@ -170,9 +223,11 @@ $ ls
     fn test_solution2() {
         assert_eq!(solution2(test_iter1()), 24933642);
     }
-}
 
-fn main() {
-    println!("Solution 1: {}", solution1(lines("input.txt")));
-    println!("Solution 2: {}", solution2(lines("input.txt")));
+    #[test]
+    fn test_parse_error() {
+        let error = Day7::parse("$ cd /\n$ ls\n12x a.txt\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(1)));
+        assert_eq!(Day7::parse("$ cd /\n$ rm a\n").unwrap_err().line, Some(2));
+    }
 }
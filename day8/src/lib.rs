@@ -0,0 +1,155 @@
+use utils::{string_lines, Direction, Grid, ParseError, Point, PointMap, PointMappable, Solution};
+
+fn visible_from(data: &PointMap, p: &Point, look_direction: &Direction) -> bool {
+    let point_height = data.at(p);
+    let points_to_look_at = data.points_in_direction(p, look_direction);
+    points_to_look_at.iter().all(|p| data.at(p) < point_height)
+}
+
+fn is_visible(data: &PointMap, p: &Point) -> bool {
+    let sizex = data.sizex();
+    let sizey = data.sizey();
+    if p.x == 0
+        || p.y == 0
+        || p.x == (sizex - 1).try_into().unwrap()
+        || p.y == (sizey - 1).try_into().unwrap()
+    {
+        return true;
+    };
+    for d in Direction::CARDINAL {
+        if visible_from(data, p, &d) {
+            return true;
+        }
+    }
+    false
+}
+
+fn trees_visible_from(map: &PointMap, p: &Point, d: &Direction) -> u32 {
+    let mut points_to_consider = map.points_in_direction(p, d);
+    if *d == Direction::North || *d == Direction::West {
+        points_to_consider.reverse();
+    };
+    let mut result = 0;
+    for considered_point in points_to_consider {
+        result += 1;
+        if map.at(&considered_point) >= map.at(p) {
+            return result;
+        }
+    }
+    result
+}
+
+fn scenic_score(map: &PointMap, p: &Point) -> u32 {
+    Direction::CARDINAL
+        .map(|d| trees_visible_from(map, p, &d))
+        .iter()
+        .cloned()
+        .reduce(|prod, count| prod * count)
+        .unwrap_or(0)
+}
+
+fn visible_trees(data: &PointMap) -> u32 {
+    let mut result = 0;
+    for p in data.all_points() {
+        if is_visible(data, &p) {
+            result += 1;
+        }
+    }
+    result
+}
+
+fn best_scenic_score(data: &PointMap) -> u32 {
+    data.all_points()
+        .map(|p| scenic_score(data, &p))
+        .max()
+        .expect("No maximum value")
+}
+
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    visible_trees(&PointMappable::load(lines))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    best_scenic_score(&PointMappable::load(lines))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = PointMap;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::from_digits(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        visible_trees(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        best_scenic_score(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{is_visible, scenic_score, solution1, solution2, trees_visible_from};
+    use utils::{string_lines, Direction, Point, PointMap, PointMappable};
+
+    fn test_iter() -> impl Iterator<Item = String> {
+        string_lines(
+            r"30373
+25512
+65332
+33549
+35390",
+        )
+    }
+
+    #[test]
+    fn test_is_not_visible() {
+        let map: PointMap = PointMappable::load(test_iter());
+        assert!(!is_visible(&map, &Point { x: 3, y: 1 }));
+    }
+
+    #[test]
+    fn trees_visible_from_test() {
+        let map: PointMap = PointMappable::load(test_iter());
+        assert_eq!(
+            trees_visible_from(&map, &Point::new(2, 1), &Direction::North),
+            1
+        );
+        assert_eq!(
+            trees_visible_from(&map, &Point::new(2, 1), &Direction::West),
+            1
+        );
+        assert_eq!(
+            trees_visible_from(&map, &Point::new(2, 1), &Direction::East),
+            2
+        );
+        assert_eq!(
+            trees_visible_from(&map, &Point::new(2, 1), &Direction::South),
+            2
+        );
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let map: PointMap = PointMappable::load(test_iter());
+        assert_eq!(scenic_score(&map, &Point::new(2, 1)), 4);
+        assert_eq!(scenic_score(&map, &Point::new(2, 3)), 8);
+    }
+
+    #[test]
+    fn test_solution1() {
+        assert_eq!(solution1(test_iter()), 21);
+    }
+
+    #[test]
+    fn test_solution2() {
+        assert_eq!(solution2(test_iter()), 8);
+    }
+}
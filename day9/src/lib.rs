@@ -1,51 +1,46 @@
 use std::collections::HashSet;
-use std::{convert::Infallible, str::FromStr};
+use std::str::FromStr;
 
-use utils::{lines, num_between, str_between, Point};
+use utils::{
+    string_lines, try_num_between, try_str_between, Direction, ParseError, Point, Solution,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum MoveDirection {
-    Up,
-    Right,
-    Left,
-    Down,
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-struct Step {
+pub struct Step {
     count: u32,
-    dir: MoveDirection,
+    dir: Direction,
 }
 
 impl Step {
-    fn new(dir: MoveDirection, count: u32) -> Self {
+    fn new(dir: Direction, count: u32) -> Self {
         Step { count, dir }
     }
 }
 
 impl FromStr for Step {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let count = num_between(s, Some(" "), None);
-        let direction = match str_between(s, None, Some(" ")) {
-            "R" => MoveDirection::Right,
-            "U" => MoveDirection::Up,
-            "D" => MoveDirection::Down,
-            "L" => MoveDirection::Left,
-            _ => panic!("Unknown direction."),
+        let count = try_num_between(s, Some(" "), None)?;
+        let direction = match try_str_between(s, None, Some(" "))? {
+            "R" => Direction::East,
+            "U" => Direction::North,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            d => return Err(ParseError::new(1, d, "one of 'R', 'U', 'D' or 'L'")),
         };
-        Ok(Step::new(direction, count.try_into().unwrap()))
+        Ok(Step::new(direction, count))
     }
 }
 
-fn parse_steps(lines: impl Iterator<Item = String>) -> Vec<Step> {
+fn parse_steps(lines: impl Iterator<Item = String>) -> Result<Vec<Step>, ParseError> {
     lines
-        .map(|l| l.parse().expect("Failed to parse step"))
+        .enumerate()
+        .map(|(idx, l)| l.parse().map_err(|e: ParseError| e.on_line(idx + 1)))
         .collect()
 }
 
-fn one_closer_to_zero(v: i32) -> i32 {
+fn one_closer_to_zero(v: isize) -> isize {
     if v == 0 {
         0
     } else if v < 0 {
@@ -58,7 +53,7 @@ fn one_closer_to_zero(v: i32) -> i32 {
 }
 
 fn adjust(h_pos: &Point, mut t_pos: Point) -> Point {
-       // Already adjacent, no adjustment needed.
+    // Already adjacent, no adjustment needed.
     if h_pos.is_adjacent(&t_pos) {
         return t_pos;
     };
@@ -81,35 +76,31 @@ fn adjust(h_pos: &Point, mut t_pos: Point) -> Point {
                 println!("H: {:?} T: {:?}", h_pos, t_pos);
                 panic!("Unknown distance combination {}:{}", xdist, ydist);
             }
-            
         };
         t_pos
     }
 }
 
-fn single_step(d: &MoveDirection, mut h_pos: Point, t_pos: Point) -> (Point, Point) {
+/// The rope moves on a plane where up, or north, is increasing `y`, unlike
+/// the grids `Direction` is made for.
+fn offset(d: &Direction) -> Point {
+    let v = d.offset();
+    Point::new(v.x, -v.y)
+}
+
+fn single_step(d: &Direction, mut h_pos: Point, t_pos: Point) -> (Point, Point) {
     if !h_pos.is_adjacent(&t_pos) {
         panic!("Head and tail are not adjacent before step.");
     };
-    match d {
-        MoveDirection::Up => h_pos += Point::new(0, 1),
-        MoveDirection::Right => h_pos += Point::new(1, 0),
-        MoveDirection::Left => h_pos -= Point::new(1, 0),
-        MoveDirection::Down => h_pos -= Point::new(0, 1),
-    };
+    h_pos += offset(d);
     (h_pos, adjust(&h_pos, t_pos))
 }
 
-fn step_rope(d: &MoveDirection, mut h_pos: Point, mut tails: Vec<Point>) -> (Point, Vec<Point>) {
+fn step_rope(d: &Direction, mut h_pos: Point, mut tails: Vec<Point>) -> (Point, Vec<Point>) {
     if !h_pos.is_adjacent(&tails[0]) {
         panic!("Head and first tail are not adjacent before step.");
     };
-    match d {
-        MoveDirection::Up => h_pos += Point::new(0, 1),
-        MoveDirection::Right => h_pos += Point::new(1, 0),
-        MoveDirection::Left => h_pos -= Point::new(1, 0),
-        MoveDirection::Down => h_pos -= Point::new(0, 1),
-    };
+    h_pos += offset(d);
     tails[0] = adjust(&h_pos, tails[0]);
     for i in 1..tails.len() {
         tails[i] = adjust(&tails[i - 1], tails[i]);
@@ -117,42 +108,70 @@ fn step_rope(d: &MoveDirection, mut h_pos: Point, mut tails: Vec<Point>) -> (Poi
     (h_pos, tails)
 }
 
-fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+fn tail_visits(steps: &[Step]) -> u32 {
     let mut result_set = HashSet::new();
     let mut h_pos = Point::new(0, 0);
     let mut t_pos = Point::new(0, 0);
     result_set.insert(t_pos);
-    for step in parse_steps(lines) {
+    for step in steps {
         for _i in 0..step.count {
             (h_pos, t_pos) = single_step(&step.dir, h_pos, t_pos);
             result_set.insert(t_pos);
         }
-    };
+    }
     result_set.len() as u32
 }
 
-fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+fn rope_tail_visits(steps: &[Step]) -> u32 {
     let mut result_set = HashSet::new();
     let mut h_pos = Point::new(0, 0);
-    let mut tails: Vec<Point> = std::iter::repeat_with(|| {
-        Point::new(0, 0)
-    }).take(9).collect();
-    for step in parse_steps(lines) {
+    let mut tails: Vec<Point> = std::iter::repeat_with(|| Point::new(0, 0))
+        .take(9)
+        .collect();
+    for step in steps {
         for _i in 0..step.count {
             let (new_h_pos, new_tails) = step_rope(&step.dir, h_pos, tails);
             result_set.insert(new_tails.last().copied().expect("No last element!"));
             h_pos = new_h_pos;
             tails = new_tails;
-            
-        };
-    };
+        }
+    }
     result_set.len() as u32
 }
 
+pub fn solution1(lines: impl Iterator<Item = String>) -> u32 {
+    tail_visits(&parse_steps(lines).expect("Failed to parse steps"))
+}
+
+pub fn solution2(lines: impl Iterator<Item = String>) -> u32 {
+    rope_tail_visits(&parse_steps(lines).expect("Failed to parse steps"))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Step>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_steps(string_lines(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Self::Answer1 {
+        tail_visits(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Self::Answer2 {
+        rope_tail_visits(input)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{parse_steps, single_step, solution1, solution2, MoveDirection, Step};
-    use utils::{string_lines, Point};
+    use crate::{parse_steps, single_step, solution1, solution2, Step};
+    use utils::{string_lines, Direction, ParseError, Point};
 
     fn test_iter() -> impl Iterator<Item = String> {
         string_lines(
@@ -172,7 +191,7 @@ R 2
     fn test_single_step_right() {
         let h_pos = Point::new(1, 0);
         let t_pos = Point::new(0, 0);
-        let (new_h_pos, new_t_pos) = single_step(&MoveDirection::Right, h_pos, t_pos);
+        let (new_h_pos, new_t_pos) = single_step(&Direction::East, h_pos, t_pos);
         assert_eq!(new_h_pos, Point::new(2, 0));
         assert_eq!(new_t_pos, Point::new(1, 0));
     }
@@ -181,7 +200,7 @@ R 2
     fn test_single_step_left() {
         let h_pos = Point::new(2, 0);
         let t_pos = Point::new(3, 0);
-        let (new_h_pos, new_t_pos) = single_step(&MoveDirection::Left, h_pos, t_pos);
+        let (new_h_pos, new_t_pos) = single_step(&Direction::West, h_pos, t_pos);
         assert_eq!(new_h_pos, Point::new(1, 0));
         assert_eq!(new_t_pos, Point::new(2, 0));
     }
@@ -190,25 +209,25 @@ R 2
     fn test_single_step_diagonal() {
         let h_pos = Point::new(4, 1);
         let t_pos = Point::new(3, 0);
-        let (new_h_pos, new_t_pos) = single_step(&MoveDirection::Up, h_pos, t_pos);
+        let (new_h_pos, new_t_pos) = single_step(&Direction::North, h_pos, t_pos);
         assert_eq!(new_h_pos, Point::new(4, 2));
         assert_eq!(new_t_pos, Point::new(4, 1));
     }
 
     #[test]
     fn parse_steps_test() {
-        let parsed = parse_steps(test_iter());
+        let parsed = parse_steps(test_iter()).unwrap();
         assert_eq!(
             parsed,
             vec![
-                Step::new(MoveDirection::Right, 4),
-                Step::new(MoveDirection::Up, 4),
-                Step::new(MoveDirection::Left, 3),
-                Step::new(MoveDirection::Down, 1),
-                Step::new(MoveDirection::Right, 4),
-                Step::new(MoveDirection::Down, 1),
-                Step::new(MoveDirection::Left, 5),
-                Step::new(MoveDirection::Right, 2)
+                Step::new(Direction::East, 4),
+                Step::new(Direction::North, 4),
+                Step::new(Direction::West, 3),
+                Step::new(Direction::South, 1),
+                Step::new(Direction::East, 4),
+                Step::new(Direction::South, 1),
+                Step::new(Direction::West, 5),
+                Step::new(Direction::East, 2)
             ]
         );
     }
@@ -222,9 +241,13 @@ R 2
     fn test_solution2() {
         assert_eq!(solution2(test_iter()), 1);
     }
-}
 
-fn main() {
-    println!("Solution 1: {}", solution1(lines("input.txt")));
-    println!("Solution 2: {}", solution2(lines("input.txt")));
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("U 2".parse(), Ok(Step::new(Direction::North, 2)));
+        assert_eq!(
+            "N 2".parse::<Step>(),
+            Err(ParseError::new(1, "N", "one of 'R', 'U', 'D' or 'L'"))
+        );
+    }
 }
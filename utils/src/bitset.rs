@@ -0,0 +1,360 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not};
+
+/// Unsigned integers that can hold the bits of a `BitSet`.
+pub trait Word:
+    Copy
+    + Eq
+    + std::hash::Hash
+    + Ord
+    + Default
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+{
+    const BITS: u32;
+    fn bit(idx: usize) -> Self;
+    fn count_ones(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+    /// `self` without its lowest set bit.
+    fn clear_lowest(self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {$(
+        impl Word for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            fn bit(idx: usize) -> Self {
+                1 << idx
+            }
+
+            fn count_ones(self) -> u32 {
+                <$t>::count_ones(self)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+
+            fn clear_lowest(self) -> Self {
+                self & self.wrapping_sub(1)
+            }
+        }
+    )*};
+}
+
+impl_word!(u64, u128);
+
+/// A set of small numbers, from 0 up to the number of bits in `W`, stored
+/// as the bits of a single integer. It is `Copy` and cheap to hash, which
+/// makes it a good part of a search state or a cache key.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet<W: Word = u64> {
+    bits: W,
+}
+
+impl<W: Word> BitSet<W> {
+    /// How many numbers fit in the set: it can hold `0..CAPACITY`.
+    pub const CAPACITY: usize = W::BITS as usize;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_bits(bits: W) -> Self {
+        Self { bits }
+    }
+
+    pub fn bits(&self) -> W {
+        self.bits
+    }
+
+    fn bit(idx: usize) -> W {
+        assert!(
+            idx < Self::CAPACITY,
+            "{} does not fit in a set of {}",
+            idx,
+            Self::CAPACITY
+        );
+        W::bit(idx)
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        idx < Self::CAPACITY && self.bits & W::bit(idx) != W::default()
+    }
+
+    /// Adds `idx`, returning whether it was new.
+    pub fn insert(&mut self, idx: usize) -> bool {
+        let new = !self.contains(idx);
+        self.bits = self.bits | Self::bit(idx);
+        new
+    }
+
+    /// Removes `idx`, returning whether it was there.
+    pub fn remove(&mut self, idx: usize) -> bool {
+        let present = self.contains(idx);
+        if present {
+            self.bits = self.bits & !W::bit(idx);
+        }
+        present
+    }
+
+    /// A copy of the set with `idx` added.
+    pub fn with(self, idx: usize) -> Self {
+        Self::from_bits(self.bits | Self::bit(idx))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == W::default()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits | other.bits)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits & other.bits)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits & !other.bits)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// The members in increasing order.
+    pub fn iter(&self) -> BitSetIter<W> {
+        BitSetIter { bits: self.bits }
+    }
+}
+
+impl<W: Word> FromIterator<usize> for BitSet<W> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<W: Word> Extend<usize> for BitSet<W> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for idx in iter {
+            self.insert(idx);
+        }
+    }
+}
+
+impl<W: Word> IntoIterator for BitSet<W> {
+    type Item = usize;
+    type IntoIter = BitSetIter<W>;
+
+    fn into_iter(self) -> BitSetIter<W> {
+        self.iter()
+    }
+}
+
+impl<W: Word> fmt::Debug for BitSet<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+pub struct BitSetIter<W: Word> {
+    bits: W,
+}
+
+impl<W: Word> Iterator for BitSetIter<W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.bits == W::default() {
+            return None;
+        }
+        let idx = self.bits.trailing_zeros() as usize;
+        self.bits = self.bits.clear_lowest();
+        Some(idx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+/// A `BitSet` without an upper bound, which grows as larger numbers are
+/// inserted.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct GrowableBitSet {
+    words: Vec<u64>,
+}
+
+impl GrowableBitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn position(idx: usize) -> (usize, u64) {
+        (idx / 64, 1 << (idx % 64))
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        let (word, bit) = Self::position(idx);
+        self.words.get(word).is_some_and(|w| w & bit != 0)
+    }
+
+    /// Adds `idx`, returning whether it was new.
+    pub fn insert(&mut self, idx: usize) -> bool {
+        let (word, bit) = Self::position(idx);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let new = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        new
+    }
+
+    /// Removes `idx`, returning whether it was there.
+    pub fn remove(&mut self, idx: usize) -> bool {
+        let present = self.contains(idx);
+        if present {
+            let (word, bit) = Self::position(idx);
+            self.words[word] &= !bit;
+            self.trim();
+        }
+        present
+    }
+
+    /// Drops trailing empty words, so equal sets compare and hash equal.
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let len = self.words.len().max(other.words.len());
+        let word = |words: &[u64], idx: usize| words.get(idx).copied().unwrap_or(0);
+        let mut result = Self {
+            words: (0..len)
+                .map(|idx| op(word(&self.words, idx), word(&other.words, idx)))
+                .collect(),
+        };
+        result.trim();
+        result
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// The members in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(idx, &word)| {
+            BitSet::<u64>::from_bits(word)
+                .iter()
+                .map(move |bit| idx * 64 + bit)
+        })
+    }
+}
+
+impl FromIterator<usize> for GrowableBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for idx in iter {
+            set.insert(idx);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for GrowableBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BitSet, GrowableBitSet};
+
+    #[test]
+    fn test_bitset() {
+        let mut set: BitSet = [3, 0, 63].into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(63));
+        assert!(!set.contains(64));
+        assert!(!set.insert(3));
+        assert!(set.remove(0));
+        assert!(!set.remove(0));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 63]);
+        assert_eq!(format!("{:?}", set), "{3, 63}");
+        let other = BitSet::new().with(3).with(5);
+        assert_eq!(set.union(&other).len(), 3);
+        assert_eq!(set.intersection(&other).iter().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(set.difference(&other).iter().collect::<Vec<_>>(), vec![63]);
+        assert!(!set.is_disjoint(&other));
+        assert!(BitSet::<u64>::new().with(5).is_subset(&other));
+        let wide = BitSet::<u128>::new().with(127);
+        assert_eq!(wide.bits(), 1 << 127);
+        assert_eq!(BitSet::<u128>::CAPACITY, 128);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bitset_overflow() {
+        BitSet::<u64>::new().insert(64);
+    }
+
+    #[test]
+    fn test_growable() {
+        let mut set: GrowableBitSet = [1, 200].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(200));
+        assert!(!set.contains(1000));
+        let small: GrowableBitSet = [1, 2].into_iter().collect();
+        assert_eq!(set.intersection(&small), [1].into_iter().collect());
+        assert_eq!(
+            set.union(&small).iter().collect::<Vec<_>>(),
+            vec![1, 2, 200]
+        );
+        assert!(set.remove(200));
+        assert_eq!(set, [1].into_iter().collect());
+        assert!(set.is_subset(&small));
+        assert!(set.difference(&small).is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+//! Finding where a long simulation starts repeating itself, so that the
+//! state after any number of steps can be worked out without running them.
+//!
+//! Both searches take the initial state, a `step` function giving the next
+//! state, and a `key` function: two states with equal keys count as the same
+//! state, so the key leaves out anything that keeps growing, like a height.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A sequence of states that, from step `start` on, repeats every `length`
+/// steps. Step 0 is the initial state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub length: usize,
+    /// The states of steps `0..=start + length`.
+    history: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The step before the first repetition that has the same key as step `n`.
+    pub fn equivalent_step(&self, n: u64) -> usize {
+        let start = self.start as u64;
+        if n <= start {
+            n as usize
+        } else {
+            (start + (n - start) % self.length as u64) as usize
+        }
+    }
+
+    /// How many whole cycles lie between step `n` and its equivalent step.
+    fn cycles_before(&self, n: u64) -> u64 {
+        n.saturating_sub(self.start as u64) / self.length as u64
+    }
+
+    /// A state with the same key as the state of step `n`.
+    pub fn state_at(&self, n: u64) -> &S {
+        &self.history[self.equivalent_step(n)]
+    }
+
+    /// The value of `metric` at step `n`, for a metric that changes by the
+    /// same amount in every cycle, such as a count or a height.
+    pub fn extrapolate(&self, n: u64, metric: impl Fn(&S) -> i64) -> i64 {
+        let per_cycle =
+            metric(&self.history[self.start + self.length]) - metric(&self.history[self.start]);
+        metric(self.state_at(n)) + per_cycle * self.cycles_before(n) as i64
+    }
+}
+
+/// Finds the cycle by remembering the key of every state in a `HashMap`.
+/// This works with a `step` that has side effects, as it is only run
+/// forwards, but loops forever if the states never repeat.
+pub fn find_cycle<S, K>(initial: S, step: impl FnMut(&S) -> S, key: impl FnMut(&S) -> K) -> Cycle<S>
+where
+    K: Eq + Hash,
+{
+    find_cycle_within(initial, step, key, usize::MAX).unwrap()
+}
+
+/// Like `find_cycle`, but gives up with `None` once `max_steps` steps have
+/// gone by without a repeated state.
+pub fn find_cycle_within<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    max_steps: usize,
+) -> Option<Cycle<S>>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![initial];
+    loop {
+        let current = history.last().unwrap();
+        let idx = history.len() - 1;
+        if let Some(start) = seen.insert(key(current), idx) {
+            return Some(Cycle {
+                start,
+                length: idx - start,
+                history,
+            });
+        }
+        if idx >= max_steps {
+            return None;
+        }
+        let next = step(current);
+        history.push(next);
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which compares keys without
+/// hashing or storing them. It runs `step` from the initial state more than
+/// once, so `step` has to be a pure function of the state.
+pub fn find_cycle_brent<S, K>(initial: S, step: impl Fn(&S) -> S, key: impl Fn(&S) -> K) -> Cycle<S>
+where
+    S: Clone,
+    K: Eq,
+{
+    // Find the cycle length by moving the tortoise to the hare at every
+    // power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while key(&tortoise) != key(&hare) {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a cycle ahead, they meet at the start of the cycle.
+    let mut history = vec![initial];
+    let mut hare = history[0].clone();
+    for _ in 0..length {
+        hare = step(&hare);
+        history.push(hare.clone());
+    }
+    let mut start = 0;
+    while key(&history[start]) != key(&hare) {
+        hare = step(&hare);
+        history.push(hare.clone());
+        start += 1;
+    }
+    Cycle {
+        start,
+        length,
+        history,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cycle::{find_cycle, find_cycle_brent, find_cycle_within};
+
+    /// 2, 4, 16, 256, 65536 % 1000 = 536, then 296, 616, 456, 936, 96, 216,
+    /// 656, 336, 896, 816, 856, 736, 696, 416, 56, 136, 496, 16 repeats.
+    fn square(n: &u64) -> u64 {
+        n * n % 1000
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(2, square, |n| *n);
+        assert_eq!((cycle.start, cycle.length), (2, 20));
+        assert_eq!(*cycle.state_at(1), 4);
+        assert_eq!(*cycle.state_at(22), 16);
+        assert_eq!(cycle.equivalent_step(1_000_000_002), 2);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 20);
+        let brent = find_cycle_brent(2, square, |n| *n);
+        assert_eq!(brent, cycle);
+        assert_eq!(find_cycle_within(2, square, |n| *n, 22), Some(cycle));
+        assert_eq!(find_cycle_within(2, square, |n| *n, 21), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A counter that goes up by one every step, keyed on its last digit.
+        let mut steps = 0;
+        let cycle = find_cycle(
+            0,
+            |total| {
+                steps += 1;
+                total + 1
+            },
+            |total| total % 10,
+        );
+        assert_eq!(steps, 10);
+        assert_eq!((cycle.start, cycle.length), (0, 10));
+        assert_eq!(cycle.extrapolate(5, |total| *total), 5);
+        assert_eq!(
+            cycle.extrapolate(1_000_000_007, |total| *total),
+            1_000_000_007
+        );
+    }
+}
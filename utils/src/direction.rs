@@ -0,0 +1,201 @@
+use std::str::FromStr;
+
+use crate::{ParseError, Point};
+
+/// A compass heading on a map where `y` grows downwards, as in a `Grid`:
+/// north is `(0, -1)` and east is `(1, 0)`. The variants go clockwise
+/// from north.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn turned(self, eighths: usize) -> Self {
+        Self::ALL[(self.index() + eighths) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turned(6)
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turned(2)
+    }
+
+    /// The direction an eighth of a turn anticlockwise.
+    pub fn turn_left_45(self) -> Self {
+        self.turned(7)
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right_45(self) -> Self {
+        self.turned(1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turned(4)
+    }
+
+    /// The vector one step in this direction moves a point by.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// The direction whose `offset` is `v`, if `v` is a unit vector.
+    pub fn from_offset(v: &Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.offset() == *v)
+    }
+
+    /// Parses the single letters `U`, `D`, `L`, `R` and `N`, `E`, `S`,
+    /// `W`, the characters `^`, `v`, `<`, `>`, and arrows.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' | '↑' => Some(Direction::North),
+            '↗' => Some(Direction::NorthEast),
+            'R' | 'E' | '>' | '→' => Some(Direction::East),
+            '↘' => Some(Direction::SouthEast),
+            'D' | 'S' | 'v' | '↓' => Some(Direction::South),
+            '↙' => Some(Direction::SouthWest),
+            'L' | 'W' | '<' | '←' => Some(Direction::West),
+            '↖' => Some(Direction::NorthWest),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(value: Direction) -> Self {
+        value.offset()
+    }
+}
+
+impl TryFrom<Point> for Direction {
+    type Error = Point;
+
+    /// Fails with the point itself if it is not a unit vector.
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        Direction::from_offset(&value).ok_or(value)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    /// Parses anything `from_char` does, or `NE`, `SE`, `SW` and `NW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let direction = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, _) => Direction::from_char(c),
+            (Some('N'), Some('E'), None) => Some(Direction::NorthEast),
+            (Some('S'), Some('E'), None) => Some(Direction::SouthEast),
+            (Some('S'), Some('W'), None) => Some(Direction::SouthWest),
+            (Some('N'), Some('W'), None) => Some(Direction::NorthWest),
+            _ => None,
+        };
+        direction.ok_or_else(|| ParseError::new(1, s, "a direction"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Direction, Point};
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right_45(), Direction::NorthWest);
+        assert_eq!(Direction::NorthEast.reverse(), Direction::SouthWest);
+        assert!(Direction::SouthEast.is_diagonal());
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.reverse().offset(), Point::new(0, 0) - d.offset());
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        for d in Direction::ALL {
+            assert_eq!(Direction::try_from(d.offset()), Ok(d));
+        }
+        assert_eq!(Point::from(Direction::South), Point::new(0, 1));
+        assert_eq!(Direction::try_from(Point::new(2, 0)), Err(Point::new(2, 0)));
+        assert_eq!(
+            Point::new(1, 1).step(Direction::NorthWest, 3),
+            Point::new(-2, -2)
+        );
+        assert_eq!(
+            Point::new(0, isize::MAX).checked_step(Direction::South, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed: Vec<Direction> = ["U", "E", "v", "<", "→", "NW", "↙"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West,
+                Direction::East,
+                Direction::NorthWest,
+                Direction::SouthWest
+            ]
+        );
+        assert!("X".parse::<Direction>().is_err());
+        assert!("NN".parse::<Direction>().is_err());
+    }
+}
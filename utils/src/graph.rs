@@ -0,0 +1,225 @@
+//! Weighted directed graphs whose nodes have string names, such as the valves
+//! and tunnels of a puzzle input. Names are interned to dense ids from 0, so
+//! the algorithms can use vectors and matrices instead of maps.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::search::bfs_all;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Vec<(usize, u32)>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `name`, which is added as a node without edges if it is new.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn ids(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// Adds an edge from `from` to `to`, replacing the weight of an existing
+    /// one. Nodes that do not exist yet are added.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: u32) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        let edges = &mut self.edges[from];
+        match edges.iter_mut().find(|(id, _)| *id == to) {
+            Some(edge) => edge.1 = weight,
+            None => edges.push((to, weight)),
+        }
+    }
+
+    /// The nodes `id` has an edge to, with the weights of those edges.
+    pub fn edges(&self, id: usize) -> &[(usize, u32)] {
+        &self.edges[id]
+    }
+
+    /// Shortest distances between all pairs of nodes.
+    pub fn floyd_warshall(&self) -> DistanceMatrix {
+        let mut distances = DistanceMatrix::new(self.len());
+        for from in self.ids() {
+            for &(to, weight) in self.edges(from) {
+                if from != to && distances.get(from, to).is_none_or(|d| weight < d) {
+                    distances.set(from, to, weight);
+                }
+            }
+        }
+        for k in self.ids() {
+            for i in self.ids() {
+                let Some(dist_i_k) = distances.get(i, k) else {
+                    continue;
+                };
+                for j in self.ids() {
+                    if let Some(dist_k_j) = distances.get(k, j) {
+                        let through_k = dist_i_k + dist_k_j;
+                        if distances.get(i, j).is_none_or(|d| through_k < d) {
+                            distances.set(i, j, through_k);
+                        }
+                    }
+                }
+            }
+        }
+        distances
+    }
+
+    /// Shortest distances between all pairs of nodes counted in edges, so
+    /// ignoring the weights. This is a breadth-first search from every node,
+    /// which beats `floyd_warshall` on sparse graphs.
+    pub fn bfs_distances(&self) -> DistanceMatrix {
+        let mut distances = DistanceMatrix::new(self.len());
+        for from in self.ids() {
+            let reached = bfs_all(from, |&id| self.edges(id).iter().map(|&(to, _)| to));
+            for (to, distance) in reached.distances {
+                distances.set(from, to, distance as u32);
+            }
+        }
+        distances
+    }
+
+    /// The graph of only the nodes for which `keep` holds, with an edge
+    /// between every two of them weighted by the shortest distance between
+    /// them in this graph. Kept nodes get new ids in the order of their old
+    /// ones.
+    pub fn compress(&self, keep: impl Fn(usize) -> bool) -> Graph {
+        let distances = self.floyd_warshall();
+        let kept: Vec<usize> = self.ids().filter(|&id| keep(id)).collect();
+        let mut result = Graph::new();
+        for &id in &kept {
+            result.intern(self.name(id));
+        }
+        for &from in &kept {
+            for &to in kept.iter().filter(|&&to| to != from) {
+                if let Some(distance) = distances.get(from, to) {
+                    result.add_edge(self.name(from), self.name(to), distance);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Distances between every two nodes of a graph, by id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMatrix {
+    size: usize,
+    cells: Vec<Option<u32>>,
+}
+
+impl DistanceMatrix {
+    /// A matrix where every node is at distance 0 from itself and no other
+    /// node can be reached.
+    fn new(size: usize) -> Self {
+        let mut cells = vec![None; size * size];
+        for id in 0..size {
+            cells[id * size + id] = Some(0);
+        }
+        Self { size, cells }
+    }
+
+    fn set(&mut self, from: usize, to: usize, distance: u32) {
+        self.cells[from * self.size + to] = Some(distance);
+    }
+
+    /// The distance from `from` to `to`, or `None` if there is no path.
+    pub fn get(&self, from: usize, to: usize) -> Option<u32> {
+        self.cells[from * self.size + to]
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graph::Graph;
+
+    /// A - B - C - D in a line, plus a long edge from A to D and a node E
+    /// that cannot be reached.
+    fn graph() -> Graph {
+        let mut graph = Graph::new();
+        for (from, to) in [("A", "B"), ("B", "C"), ("C", "D")] {
+            graph.add_edge(from, to, 2);
+            graph.add_edge(to, from, 2);
+        }
+        graph.add_edge("A", "D", 7);
+        graph.intern("E");
+        graph
+    }
+
+    #[test]
+    fn test_intern() {
+        let mut graph = graph();
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.intern("C"), 2);
+        assert_eq!(graph.id("E"), Some(4));
+        assert_eq!(graph.id("F"), None);
+        assert_eq!(graph.name(1), "B");
+        graph.add_edge("A", "B", 3);
+        assert_eq!(graph.edges(0), &[(1, 3), (3, 7)]);
+    }
+
+    #[test]
+    fn test_distances() {
+        let graph = graph();
+        let weighted = graph.floyd_warshall();
+        assert_eq!(weighted.get(0, 3), Some(6));
+        assert_eq!(weighted.get(3, 0), Some(6));
+        assert_eq!(weighted.get(2, 2), Some(0));
+        assert_eq!(weighted.get(0, 4), None);
+        let unweighted = graph.bfs_distances();
+        assert_eq!(unweighted.get(0, 3), Some(1));
+        assert_eq!(unweighted.get(3, 0), Some(3));
+        assert_eq!(unweighted.get(4, 0), None);
+    }
+
+    #[test]
+    fn test_compress() {
+        let graph = graph();
+        let compressed = graph.compress(|id| graph.name(id) != "B");
+        assert_eq!(compressed.len(), 4);
+        let a = compressed.id("A").unwrap();
+        let c = compressed.id("C").unwrap();
+        let d = compressed.id("D").unwrap();
+        assert_eq!(compressed.edges(a), &[(c, 4), (d, 6)]);
+        assert!(compressed.edges(compressed.id("E").unwrap()).is_empty());
+    }
+}
@@ -0,0 +1,328 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Neighbourhood, Neighbours, ParseError, Point, Topology};
+
+/// A rectangular map of cells stored row by row in a single `Vec`. Points
+/// address cells with `x` as the column and `y` as the row, both from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseError> {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            grid.push_row(row)?;
+        }
+        Ok(grid)
+    }
+
+    /// Builds a grid with one row per non-empty line, mapping every character
+    /// with `transform`.
+    pub fn from_chars(
+        lines: impl Iterator<Item = String>,
+        mut transform: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        Self::try_from_chars(lines, |c| Some(transform(c)), "")
+    }
+
+    /// Like `from_chars`, but `transform` may reject a character, which is
+    /// reported as a `ParseError` that expected `expected`.
+    pub fn try_from_chars(
+        lines: impl Iterator<Item = String>,
+        mut transform: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let rows = lines
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        transform(c).ok_or_else(|| {
+                            ParseError::new(col + 1, &c.to_string(), expected).on_line(idx + 1)
+                        })
+                    })
+                    .collect::<Result<Vec<T>, ParseError>>()
+                    .map(|row| (idx, row))
+            });
+        let mut grid = Self::from_rows([])?;
+        for row in rows {
+            let (idx, row) = row?;
+            grid.push_row(row).map_err(|e| e.on_line(idx + 1))?;
+        }
+        Ok(grid)
+    }
+
+    fn push_row(&mut self, row: Vec<T>) -> Result<(), ParseError> {
+        if self.height == 0 {
+            self.width = row.len();
+        } else if row.len() != self.width {
+            return Err(ParseError::new(
+                self.width.min(row.len()) + 1,
+                &format!("{} cells", row.len()),
+                &format!("a row of {} cells", self.width),
+            )
+            .on_line(self.height + 1));
+        }
+        self.cells.extend(row);
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, p: &Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        self.offset(p).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.offset(p).map(|offset| &mut self.cells[offset])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.width * self.height)
+            .map(move |offset| Point::new((offset % width) as isize, (offset / width) as isize))
+    }
+
+    /// All cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a size of 0, which an empty grid would give.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn neighbours<'a>(
+        &self,
+        p: &Point,
+        neighbourhood: Neighbourhood<'a>,
+        topology: Topology,
+    ) -> Neighbours<'a> {
+        Neighbours::new(*p, neighbourhood, topology, self.width, self.height)
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, transform: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(transform).collect(),
+        }
+    }
+
+    /// A `width` by `height` grid where the cell at `(x, y)` is a copy of
+    /// this grid's cell at `source(x, y)`.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|offset| {
+                let (x, y) = source(offset % width, offset / width);
+                self.cells[y * self.width + x].clone()
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// The grid mirrored along the diagonal from the top left corner, so
+    /// rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// The grid turned clockwise by `quarter_turns` quarters.
+    pub fn rotate(&self, quarter_turns: usize) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        match quarter_turns % 4 {
+            0 => self.clone(),
+            1 => self.rearranged(self.height, self.width, |x, y| (y, height - 1 - x)),
+            n => self.rotate(1).rotate(n - 1),
+        }
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_h(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_v(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+
+    /// The eight ways to rotate and mirror the grid: the four rotations,
+    /// starting with the grid itself, then the same rotations mirrored left
+    /// to right. A symmetric grid gives some of them more than once.
+    pub fn symmetries(&self) -> Vec<Self>
+    where
+        T: Clone,
+    {
+        let rotations: Vec<Self> = (0..4).map(|turns| self.rotate(turns)).collect();
+        let flipped: Vec<Self> = rotations.iter().map(Self::flip_h).collect();
+        rotations.into_iter().chain(flipped).collect()
+    }
+}
+
+impl Grid<u32> {
+    pub fn from_digits(lines: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        Self::try_from_chars(lines, |c| c.to_digit(10), "a digit")
+    }
+}
+
+impl<T> Index<&Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: &Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<&Point> for Grid<T> {
+    fn index_mut(&mut self, p: &Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{string_lines, Grid, ParseError, Point};
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, -1)), None);
+        assert_eq!(grid.rows().nth(1), Some(&[4, 5, 6][..]));
+        assert_eq!(
+            Grid::from_rows([vec![1, 2, 3], vec![4, 5]]),
+            Err(ParseError::new(3, "2 cells", "a row of 3 cells").on_line(2))
+        );
+    }
+
+    #[test]
+    fn test_from_chars() {
+        let grid = Grid::from_chars(string_lines("S.#\n..E\n"), |c| c).unwrap();
+        assert_eq!(grid.position(|&c| c == 'E'), Some(Point::new(2, 1)));
+        assert!(grid.map(|&c| c == '#')[&Point::new(2, 0)]);
+        assert_eq!(
+            Grid::from_digits(string_lines("12\n3x\n")),
+            Err(ParseError::new(2, "x", "a digit").on_line(2))
+        );
+        let empty = Grid::from_digits(string_lines("")).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.points().count(), 0);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        *grid.get_mut(&Point::new(1, 0)).unwrap() += 5;
+        grid[&Point::new(0, 1)] = 7;
+        assert_eq!(
+            grid.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+            vec![0, 5, 7, 0]
+        );
+        assert!(grid.get_mut(&Point::new(2, 2)).is_none());
+    }
+
+    #[test]
+    fn test_transforms() {
+        // 1 2 3
+        // 4 5 6
+        let grid = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let rows = |g: &Grid<i32>| g.rows().map(|r| r.to_vec()).collect::<Vec<_>>();
+        assert_eq!(
+            rows(&grid.transpose()),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            rows(&grid.rotate(1)),
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+        );
+        assert_eq!(rows(&grid.rotate(2)), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+        assert_eq!(grid.rotate(3), grid.rotate(1).rotate(2));
+        assert_eq!(grid.rotate(4), grid);
+        assert_eq!(rows(&grid.flip_h()), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+        assert_eq!(rows(&grid.flip_v()), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        let symmetries = grid.symmetries();
+        assert_eq!(symmetries.len(), 8);
+        assert!(symmetries.contains(&grid.transpose()));
+        assert!(symmetries.contains(&grid.flip_v()));
+        assert_eq!(Grid::new(2, 2, 0).symmetries()[5], Grid::new(2, 2, 0));
+    }
+}
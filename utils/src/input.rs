@@ -0,0 +1,155 @@
+use flate2::read::GzDecoder;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+use crate::{Blocks, ParseError};
+
+/// A source of puzzle input: a file (gzip-compressed when its name ends in
+/// `.gz`), stdin, or text that is already in memory, such as a string from
+/// `include_str!`. Reading consumes the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input<'a> {
+    Path(PathBuf),
+    Stdin,
+    Text(Cow<'a, str>),
+}
+
+impl<'a> Input<'a> {
+    /// A file on disk, or stdin when `path` is `-`.
+    pub fn path(path: &str) -> Self {
+        if path == "-" {
+            Input::Stdin
+        } else {
+            Input::Path(PathBuf::from(path))
+        }
+    }
+
+    pub fn stdin() -> Self {
+        Input::Stdin
+    }
+
+    pub fn text(text: impl Into<Cow<'a, str>>) -> Self {
+        Input::Text(text.into())
+    }
+
+    pub fn reader(self) -> Result<Box<dyn BufRead + 'a>, ParseError> {
+        let name = self.to_string();
+        let unreadable = |e: io::Error| ParseError::unreadable(&name, "a readable file", &e);
+        Ok(match self {
+            Input::Path(path) => {
+                let file = File::open(&path).map_err(unreadable)?;
+                if path.extension().is_some_and(|ext| ext == "gz") {
+                    Box::new(BufReader::new(GzDecoder::new(file)))
+                } else {
+                    Box::new(BufReader::new(file))
+                }
+            }
+            Input::Stdin => Box::new(io::stdin().lock()),
+            Input::Text(Cow::Borrowed(text)) => Box::new(text.as_bytes()),
+            Input::Text(Cow::Owned(text)) => Box::new(io::Cursor::new(text.into_bytes())),
+        })
+    }
+
+    pub fn read_to_string(self) -> Result<String, ParseError> {
+        if let Input::Text(text) = self {
+            return Ok(text.into_owned());
+        }
+        let name = self.to_string();
+        let mut result = String::new();
+        self.reader()?
+            .read_to_string(&mut result)
+            .map_err(|e| ParseError::unreadable(&name, "readable text", &e))?;
+        Ok(result)
+    }
+
+    pub fn try_lines(
+        self,
+    ) -> Result<impl Iterator<Item = Result<String, ParseError>> + 'a, ParseError> {
+        Ok(self.reader()?.lines().enumerate().map(|(idx, elem)| {
+            elem.map_err(|e| ParseError::unreadable("", "a readable line", &e).on_line(idx + 1))
+        }))
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = String> + 'a {
+        self.try_lines()
+            .unwrap_or_else(|e| panic!("Failed to open input: {}", e))
+            .map(|elem| elem.unwrap_or_else(|e| panic!("Couldn't read line: {}", e)))
+    }
+
+    pub fn split_lines(self) -> impl Iterator<Item = Vec<String>> + 'a {
+        self.lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.split_whitespace().map(String::from).collect())
+    }
+
+    pub fn split_lines_sep(self, sep: char) -> impl Iterator<Item = Vec<String>> + 'a {
+        self.lines()
+            .filter(|s| !s.is_empty())
+            .map(move |s| s.split(sep).map(String::from).collect())
+    }
+
+    pub fn char_lines(self) -> impl Iterator<Item = Vec<char>> + 'a {
+        self.lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.chars().collect())
+    }
+
+    pub fn blocks(self) -> Blocks<impl Iterator<Item = String> + 'a> {
+        Blocks::new(self.lines())
+    }
+}
+
+impl Display for Input<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Path(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    use crate::Input;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(Input::path("-"), Input::Stdin);
+        assert_eq!(Input::path("day1/input.txt").to_string(), "day1/input.txt");
+        assert!(Input::path("does/not/exist.txt").try_lines().is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        let owned = Input::text(String::from("a b\n\nc,d\n"));
+        assert_eq!(
+            owned.split_lines().collect::<Vec<_>>(),
+            vec![vec!["a", "b"], vec!["c,d"]]
+        );
+        let embedded = Input::text("a b\n\nc,d");
+        assert_eq!(embedded.split_lines_sep(',').count(), 2);
+        assert_eq!(
+            Input::text("ab\ncd").char_lines().last(),
+            Some(vec!['c', 'd'])
+        );
+    }
+
+    #[test]
+    fn test_gzip() {
+        let path = std::env::temp_dir().join(format!("utils-input-{}.txt.gz", std::process::id()));
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"1\n2\n\n3\n").unwrap();
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+        let blocks: Vec<_> = Input::path(path.to_str().unwrap()).blocks().collect();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(blocks, vec![vec!["1", "2"], vec!["3"]]);
+    }
+}
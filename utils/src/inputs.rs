@@ -0,0 +1,233 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Time of the last request made by any `InputCache` in this process, so that
+/// the rate limit holds no matter how many caches are created.
+static LAST_FETCH: Mutex<Option<Instant>> = Mutex::new(None);
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    NoSession,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "{}", e),
+            InputError::NoSession => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {}",
+                session_file().map_or("a session file".to_string(), |p| p.display().to_string())
+            ),
+            InputError::Status(code, body) => {
+                write!(f, "server answered {}: {}", code, body.trim())
+            }
+            InputError::Transport(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+/// Session file read when `AOC_SESSION` is not set: `AOC_SESSION_FILE`, or
+/// `~/.config/aoc/session`.
+fn session_file() -> Option<PathBuf> {
+    env::var_os("AOC_SESSION_FILE")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/aoc/session")))
+}
+
+/// Puzzle inputs stored as `<dir>/<year>/<day>.txt`. Inputs that are not
+/// cached yet are downloaded from `<base_url>/<year>/day/<day>/input` and
+/// written to the cache, so every input is fetched at most once.
+pub struct InputCache {
+    pub dir: PathBuf,
+    pub base_url: String,
+    pub session: Option<String>,
+    /// Minimum time between two requests to the server.
+    pub min_interval: Duration,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            min_interval: Duration::from_secs(3),
+        }
+    }
+
+    /// Cache in `AOC_INPUT_DIR` (default `inputs`), fetching from
+    /// `AOC_BASE_URL` with the token from `AOC_SESSION` or the session file.
+    pub fn from_env() -> Self {
+        let mut cache =
+            Self::new(env::var("AOC_INPUT_DIR").unwrap_or_else(|_| "inputs".to_string()));
+        if let Ok(url) = env::var("AOC_BASE_URL") {
+            cache.base_url = url;
+        }
+        cache.session = env::var("AOC_SESSION").ok().or_else(|| {
+            session_file()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .map(|token| token.trim().to_string())
+        });
+        cache
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{}.txt", day))
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            Err(_) => {}
+        }
+        let input = self.fetch(year, day)?;
+        fs::create_dir_all(path.parent().expect("Cache path has a parent"))?;
+        fs::write(&path, &input)?;
+        Ok(input)
+    }
+
+    fn fetch(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let session = self.session.as_ref().ok_or(InputError::NoSession)?;
+        self.wait_for_turn();
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let response = ureq::get(&url)
+            .timeout(Duration::from_secs(30))
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "aoc input cache")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, response) => {
+                    InputError::Status(code, response.into_string().unwrap_or_default())
+                }
+                ureq::Error::Transport(e) => InputError::Transport(e.to_string()),
+            })?;
+        Ok(response.into_string()?)
+    }
+
+    fn wait_for_turn(&self) {
+        let mut last = LAST_FETCH.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(elapsed) = last.map(|t| t.elapsed()) {
+            if elapsed < self.min_interval {
+                sleep(self.min_interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::inputs::{InputCache, InputError};
+
+    /// Serves `body` with `status` to every request carrying the session
+    /// cookie `secret`, and 400 to the others. Returns the base URL and the
+    /// number of requests answered so far.
+    fn stub_server(status: u16, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut has_cookie = false;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    has_cookie |= line.to_lowercase().starts_with("cookie: session=secret");
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = if has_cookie {
+                    (status, body)
+                } else {
+                    (400, "no cookie")
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn cache(name: &str, url: &str) -> InputCache {
+        let dir =
+            std::env::temp_dir().join(format!("utils-inputs-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut cache = InputCache::new(dir);
+        cache.base_url = url.to_string();
+        cache.session = Some("secret".to_string());
+        cache.min_interval = Duration::ZERO;
+        cache
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, requests) = stub_server(200, "1\n2\n");
+        let cache = cache("fetch", &url);
+        assert_eq!(cache.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(cache.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(cache.path(2022, 1).ends_with("2022/1.txt"));
+        assert!(cache.path(2022, 1).exists());
+        std::fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, requests) = stub_server(404, "Not found");
+        let mut cache = cache("errors", &url);
+        assert!(matches!(
+            cache.get(2022, 26),
+            Err(InputError::Status(404, _))
+        ));
+        assert!(!cache.path(2022, 26).exists());
+        cache.session = None;
+        assert!(matches!(cache.get(2022, 26), Err(InputError::NoSession)));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
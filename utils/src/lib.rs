@@ -1,13 +1,33 @@
+mod bitset;
+pub mod cycle;
+mod direction;
+pub mod graph;
+mod grid;
+mod input;
+pub mod inputs;
+mod line;
+mod memo;
+mod neighbours;
 mod parsing;
 mod point;
+mod point3;
 mod pointmap;
+pub mod search;
+mod solution;
 mod sparsemap;
-mod line;
+pub use bitset::{BitSet, BitSetIter, GrowableBitSet, Word};
+pub use direction::Direction;
+pub use grid::Grid;
+pub use input::Input;
+pub use line::*;
+pub use memo::Memo;
+pub use neighbours::{Neighbourhood, Neighbours, Topology};
 pub use parsing::*;
-pub use point::Point;
-pub use pointmap::{LookDirection, PointMap, PointMappable};
+pub use point::{Bounds, Coord, Point};
+pub use point3::{Bounds3, Point3};
+pub use pointmap::{PointMap, PointMappable};
+pub use solution::Solution;
 pub use sparsemap::*;
-pub use line::*;
 
 #[macro_use]
 extern crate lazy_static;
@@ -16,26 +36,131 @@ extern crate lazy_static;
 mod test {
     use std::collections::HashSet;
 
-    use crate::{num_between, string_lines, LookDirection, Point, PointMap, PointMappable};
+    use crate::{
+        blocks_with, num_between, numbers_on_line, string_blocks, string_lines, try_lines,
+        try_num_between, try_numbers_on_line, try_str_between, Direction, Grid, ParseError, Point,
+        PointMap, PointMappable,
+    };
 
     #[test]
     fn test_num_between_simple() {
-        let result = num_between("move 1 from 2 to 3", Some("move "), Some(" from "));
+        let result: i32 = num_between("move 1 from 2 to 3", Some("move "), Some(" from "));
         assert_eq!(result, 1);
     }
 
     #[test]
     fn test_num_between_simple_2() {
-        let result = num_between("move 1 from 2 to 3", Some("from "), Some(" to "));
+        let result: i32 = num_between("move 1 from 2 to 3", Some("from "), Some(" to "));
         assert_eq!(result, 2);
     }
 
     #[test]
     fn test_num_between_to_end() {
-        let result = num_between("move 1 from 2 to 3", Some(" to "), None);
+        let result: i32 = num_between("move 1 from 2 to 3", Some(" to "), None);
         assert_eq!(result, 3);
     }
 
+    #[test]
+    fn test_try_str_between_missing_separator() {
+        assert_eq!(
+            try_str_between("move 1 from 2 to 3", Some("take "), None),
+            Err(ParseError::new(1, "move 1 from 2 to 3", "'take '"))
+        );
+        assert_eq!(
+            try_str_between("move 1 from 2 to 3", Some("to "), Some(" from")),
+            Err(ParseError::new(18, "3", "' from'"))
+        );
+    }
+
+    #[test]
+    fn test_try_num_between_bad_number() {
+        let err =
+            try_num_between::<i32>("move x from 2 to 3", Some("move "), Some(" from")).unwrap_err();
+        assert_eq!(err, ParseError::new(6, "x", "an integer"));
+        assert_eq!(
+            err.on_line(4).to_string(),
+            "line 4, column 6: expected an integer, found 'x'"
+        );
+    }
+
+    #[test]
+    fn test_numbers_on_line() {
+        let line = "Sensor at x=-2, y=18: closest beacon is at x=2-4";
+        assert_eq!(numbers_on_line::<isize>(line), vec![-2, 18, 2, 4]);
+        assert_eq!(numbers_on_line::<u32>("at x=2, y=18"), vec![2, 18]);
+        assert_eq!(numbers_on_line::<u32>("x=2-4"), vec![2, 4]);
+        assert_eq!(numbers_on_line::<i32>("from -3 to 5-7"), vec![-3, 5, 7]);
+        assert_eq!(
+            try_numbers_on_line::<u32>(line),
+            Err(ParseError::new(13, "-2", "an unsigned integer"))
+        );
+        assert_eq!(
+            numbers_on_line::<f64>("0.5, -1.25 and 3"),
+            vec![0.5, -1.25, 3.0]
+        );
+        assert_eq!(
+            numbers_on_line::<u64>("Starting items: 10000000000, 7"),
+            vec![10000000000, 7]
+        );
+        assert_eq!(
+            numbers_on_line::<i128>("-170141183460469231731687303715884105728"),
+            vec![i128::MIN]
+        );
+    }
+
+    #[test]
+    fn test_number_overflow() {
+        assert_eq!(
+            try_numbers_on_line::<u8>("1, 256"),
+            Err(ParseError::new(4, "256", "a value that fits in u8"))
+        );
+        assert_eq!(
+            try_num_between::<u32>("move -1 from 2", Some("move "), Some(" from")),
+            Err(ParseError::new(6, "-1", "an unsigned integer"))
+        );
+    }
+
+    #[test]
+    fn test_try_lines_missing_file() {
+        let err = try_lines("does/not/exist.txt").err().unwrap();
+        assert_eq!((err.line, err.column), (None, None));
+        assert!(err
+            .to_string()
+            .starts_with("expected a readable file (No such file"));
+    }
+
+    #[test]
+    fn test_string_blocks() {
+        let expected = vec![vec!["1", "2"], vec!["3"]];
+        assert_eq!(string_blocks("1\n2\n\n3\n").collect::<Vec<_>>(), expected);
+        assert_eq!(string_blocks("1\n2\n\n3").collect::<Vec<_>>(), expected);
+        assert_eq!(
+            string_blocks("\n1\n2\n\n\n3\n\n").collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(string_blocks("").count(), 0);
+    }
+
+    #[test]
+    fn test_blocks_with() {
+        let parse_sum = |block: &[String]| -> Result<u32, ParseError> {
+            block
+                .iter()
+                .enumerate()
+                .map(|(idx, line)| {
+                    try_num_between::<u32>(line, None, None).map_err(|e| e.on_line(idx + 1))
+                })
+                .sum()
+        };
+        let sums: Result<Vec<u32>, ParseError> =
+            blocks_with(string_lines("1\n2\n\n3\n"), parse_sum).collect();
+        assert_eq!(sums, Ok(vec![3, 3]));
+        let err = blocks_with(string_lines("\n1\n\n2\nx\n"), parse_sum)
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(err.line, Some(5));
+    }
+
     #[test]
     fn test_map_construction() {
         let m: PointMap = PointMappable::load(string_lines(
@@ -50,13 +175,14 @@ mod test {
         assert_eq!(m.sizey(), 5);
         assert_eq!(
             m,
-            vec![
+            Grid::from_rows(vec![
                 vec![3, 0, 3, 7, 3],
                 vec![2, 5, 5, 1, 2],
                 vec![6, 5, 3, 3, 2],
                 vec![3, 3, 5, 4, 9],
                 vec![3, 5, 3, 9, 0]
-            ]
+            ])
+            .unwrap()
         );
     }
 
@@ -82,16 +208,23 @@ mod test {
             vec![3, 3, 5, 4, 9],
             vec![3, 5, 3, 9, 0],
         ];
-        let points_north = m.points_in_direction(&Point::new(2, 2), &LookDirection::ToNorth);
-        let points_east = m.points_in_direction(&Point::new(2, 2), &LookDirection::ToEast);
-        let points_south = m.points_in_direction(&Point::new(2, 2), &LookDirection::ToSouth);
-        let points_west = m.points_in_direction(&Point::new(2, 2), &LookDirection::ToWest);
+        let points_north = m.points_in_direction(&Point::new(2, 2), &Direction::North);
+        let points_east = m.points_in_direction(&Point::new(2, 2), &Direction::East);
+        let points_south = m.points_in_direction(&Point::new(2, 2), &Direction::South);
+        let points_west = m.points_in_direction(&Point::new(2, 2), &Direction::West);
         assert_eq!(points_north, vec![Point::new(2, 0), Point::new(2, 1)]);
         assert_eq!(points_east, vec![Point::new(3, 2), Point::new(4, 2)]);
         assert_eq!(points_south, vec![Point::new(2, 3), Point::new(2, 4)]);
         assert_eq!(points_west, vec![Point::new(0, 2), Point::new(1, 2)]);
     }
 
+    #[test]
+    #[should_panic(expected = "only cardinal directions")]
+    fn test_points_in_diagonal_direction() {
+        let m = vec![vec![1, 2], vec![3, 4]];
+        m.points_in_direction(&Point::new(0, 0), &Direction::SouthEast);
+    }
+
     #[test]
     fn test_adjacent_points() {
         let m = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
@@ -112,13 +245,27 @@ mod test {
 
     #[test]
     fn test_all_points() {
-        let m: PointMap = vec![
+        let m: PointMap = Grid::from_rows(vec![
             vec![3, 0, 3, 7, 3],
             vec![2, 5, 5, 1, 2],
             vec![6, 5, 3, 3, 2],
             vec![3, 3, 5, 4, 9],
             vec![3, 5, 3, 9, 0],
-        ];
+        ])
+        .unwrap();
         assert_eq!(m.all_points().count(), 25);
+        assert_eq!(Vec::<Vec<u32>>::new().sizex(), 0);
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let row_major = vec![
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(2, 0),
+            Point::new(0, 1),
+            Point::new(1, 1),
+            Point::new(2, 1),
+        ];
+        assert_eq!(rows.all_points().collect::<Vec<_>>(), row_major);
+        let grid: PointMap = Grid::from_rows(rows).unwrap();
+        assert_eq!(grid.all_points().collect::<Vec<_>>(), row_major);
     }
 }
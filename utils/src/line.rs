@@ -1,7 +1,55 @@
-use std::cmp::{max, min};
+use std::cmp::{max, min, Ordering};
 
-use crate::Point;
+use crate::{Bounds, Point};
 
+/// The z component of the cross product of `a` and `b`, which is 0 when
+/// they are parallel.
+fn cross(a: &Point, b: &Point) -> i128 {
+    a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `a / b` rounded up, for positive `b`.
+fn div_ceil_i128(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
+
+/// How a `Line` runs across the grid.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum LineKind {
+    /// Along a row. A line from a point to itself counts as horizontal.
+    Horizontal,
+    /// Along a column.
+    Vertical,
+    /// At 45°, moving one column for every row.
+    Diagonal,
+    /// At any other angle.
+    General,
+}
+
+/// Where two lines meet.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Intersection {
+    Point(Point),
+    /// Where two lines cross between grid points, at `(x / denom, y / denom)`,
+    /// where `x`, `y` and `denom` have no common factor and `denom` is above 1.
+    OffGrid {
+        x: i128,
+        y: i128,
+        denom: i128,
+    },
+    /// The part shared by two overlapping collinear lines.
+    Segment(Line),
+}
+
+/// The segment between two points, both included.
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct Line {
     p1: Point,
@@ -10,27 +58,269 @@ pub struct Line {
 
 impl Line {
     pub fn new(p1: &Point, p2: &Point) -> Self {
-        if p1.x != p2.x && p1.y != p2.y {
-            panic!("Can only create orthogonal lines.");
-        }
         Line { p1: *p1, p2: *p2 }
     }
 
+    pub fn start(&self) -> Point {
+        self.p1
+    }
+
+    pub fn end(&self) -> Point {
+        self.p2
+    }
+
+    pub fn kind(&self) -> LineKind {
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        if dy == 0 {
+            LineKind::Horizontal
+        } else if dx == 0 {
+            LineKind::Vertical
+        } else if dx.abs() == dy.abs() {
+            LineKind::Diagonal
+        } else {
+            LineKind::General
+        }
+    }
+
+    pub fn is_orthogonal(&self) -> bool {
+        matches!(self.kind(), LineKind::Horizontal | LineKind::Vertical)
+    }
+
+    /// The euclidean distance between the end points.
+    pub fn length(&self) -> f64 {
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        (dx as f64).hypot(dy as f64)
+    }
+
+    /// The grid points closest to the line from `start` to `end`, as drawn by
+    /// Bresenham's algorithm. On orthogonal and diagonal lines these are
+    /// exactly the points `on` the line.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = Point> {
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        Bresenham {
+            next: Some(self.p1),
+            end: self.p2,
+            dx: dx.abs(),
+            dy: -dy.abs(),
+            step: Point::new(dx.signum(), dy.signum()),
+            err: dx.abs() - dy.abs(),
+        }
+    }
+
+    /// Whether `p` lies exactly on the segment.
     pub fn on(&self, p: &Point) -> bool {
-        (p.x == self.p1.x && p.y >= min(self.p1.y, self.p2.y) && p.y <= max(self.p1.y, self.p2.y)) ||
-            (p.y == self.p1.y && p.x >= min(self.p1.x, self.p2.x) && p.x <= max(self.p1.x, self.p2.x))
+        cross(&(self.p2 - self.p1), &(*p - self.p1)) == 0
+            && p.x >= min(self.p1.x, self.p2.x)
+            && p.x <= max(self.p1.x, self.p2.x)
+            && p.y >= min(self.p1.y, self.p2.y)
+            && p.y <= max(self.p1.y, self.p2.y)
     }
 
+    /// Whether `p` lies on the infinite line through both end points, or
+    /// is the end point of a line from a point to itself.
     pub fn on_infinite(&self, p: &Point) -> bool {
-        (p.x == self.p1.x && p.x == self.p2.x) ||
-            (p.y == self.p1.y && p.y == self.p2.y)
+        if self.p1 == self.p2 {
+            return *p == self.p1;
+        }
+        cross(&(self.p2 - self.p1), &(*p - self.p1)) == 0
+    }
+
+    /// Whether both lines lie on the same infinite line.
+    pub fn is_collinear(&self, other: &Line) -> bool {
+        if self.p1 == self.p2 {
+            other.on_infinite(&self.p1)
+        } else {
+            self.on_infinite(&other.p1) && self.on_infinite(&other.p2)
+        }
     }
+
+    /// For lines that are not parallel, where they cross as the fraction
+    /// `num / denom` of the way from `start` to `end` of this line, if they
+    /// cross within both segments.
+    fn crossing(&self, other: &Line) -> Option<(i128, i128)> {
+        let d1 = self.p2 - self.p1;
+        let d2 = other.p2 - other.p1;
+        let mut denom = cross(&d1, &d2);
+        if denom == 0 {
+            return None;
+        }
+        let offset = other.p1 - self.p1;
+        let (mut t, mut u) = (cross(&offset, &d2), cross(&offset, &d1));
+        if denom < 0 {
+            (denom, t, u) = (-denom, -t, -u);
+        }
+        ((0..=denom).contains(&t) && (0..=denom).contains(&u)).then_some((t, denom))
+    }
+
+    /// Whether the segments share at least one point, which need not be a
+    /// grid point.
+    pub fn intersects(&self, other: &Line) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The point or segment both lines share, if any.
+    pub fn intersection(&self, other: &Line) -> Option<Intersection> {
+        if self.p1 == self.p2 {
+            return other.on(&self.p1).then_some(Intersection::Point(self.p1));
+        }
+        if other.p1 == other.p2 {
+            return self.on(&other.p1).then_some(Intersection::Point(other.p1));
+        }
+        if let Some((t, denom)) = self.crossing(other) {
+            let d = self.p2 - self.p1;
+            let x = self.p1.x as i128 * denom + d.x as i128 * t;
+            let y = self.p1.y as i128 * denom + d.y as i128 * t;
+            let g = gcd(gcd(x.unsigned_abs(), y.unsigned_abs()), denom as u128) as i128;
+            let (x, y, denom) = (x / g, y / g, denom / g);
+            if denom == 1 {
+                return Some(Intersection::Point(Point::new(x as isize, y as isize)));
+            }
+            return Some(Intersection::OffGrid { x, y, denom });
+        }
+        if !self.is_collinear(other) {
+            return None;
+        }
+        // Order points along the line by the coordinate that changes most.
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        let key = |p: &Point| if dx.abs() >= dy.abs() { p.x } else { p.y };
+        let sorted = |l: &Line| {
+            if key(&l.p1) <= key(&l.p2) {
+                (l.p1, l.p2)
+            } else {
+                (l.p2, l.p1)
+            }
+        };
+        let ((a, b), (c, d)) = (sorted(self), sorted(other));
+        let from = if key(&a) >= key(&c) { a } else { c };
+        let to = if key(&b) <= key(&d) { b } else { d };
+        match key(&from).cmp(&key(&to)) {
+            Ordering::Less => Some(Intersection::Segment(Line::new(&from, &to))),
+            Ordering::Equal => Some(Intersection::Point(from)),
+            Ordering::Greater => None,
+        }
+    }
+
+    /// The part of the line within `bounds`, edges included: from the first
+    /// to the last of `points()` inside them. Orthogonal and diagonal lines
+    /// are cut exactly; the clipped end points of other lines lie on this
+    /// line's raster, which the clipped line may round differently between
+    /// them.
+    pub fn clip(&self, bounds: &Bounds) -> Option<Line> {
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        let x_major = dx.abs() >= dy.abs();
+        // Work along the axis `points()` takes a step on every time.
+        let major_minor = |p: &Point| if x_major { (p.x, p.y) } else { (p.y, p.x) };
+        let (d_major, d_minor) = if x_major { (dx, dy) } else { (dy, dx) };
+        let (start_major, start_minor) = major_minor(&self.p1);
+        let (a, b) = (
+            d_major.unsigned_abs() as i128,
+            d_minor.unsigned_abs() as i128,
+        );
+        if a == 0 {
+            return bounds.contains(&self.p1).then_some(*self);
+        }
+        let (min_major, min_minor) = major_minor(&bounds.min);
+        let (max_major, max_minor) = major_minor(&bounds.max);
+        // Offsets from the start along each axis that stay within bounds.
+        let range = |start: isize, d: isize, min: isize, max: isize| {
+            let (low, high) = (min as i128 - start as i128, max as i128 - start as i128);
+            if d >= 0 {
+                (low, high)
+            } else {
+                (-high, -low)
+            }
+        };
+        let (major_low, major_high) = range(start_major, d_major, min_major, max_major);
+        let (minor_low, minor_high) = range(start_minor, d_minor, min_minor, max_minor);
+        // After k steps, the minor axis has moved floor((2bk + a) / 2a).
+        let (mut from, mut to) = (major_low.max(0), major_high.min(a));
+        if b == 0 {
+            if minor_low > 0 || minor_high < 0 {
+                return None;
+            }
+        } else {
+            from = from.max(div_ceil_i128(2 * a * minor_low - a, 2 * b));
+            to = to.min(div_ceil_i128(2 * a * minor_high + a, 2 * b) - 1);
+        }
+        let point_at = |k: i128| {
+            let moved =
+                |start: isize, d: isize, n: i128| (start as i128 + d.signum() as i128 * n) as isize;
+            let m = moved(start_major, d_major, k);
+            let n = moved(start_minor, d_minor, (2 * b * k + a).div_euclid(2 * a));
+            if x_major {
+                Point::new(m, n)
+            } else {
+                Point::new(n, m)
+            }
+        };
+        (from <= to).then(|| Line::new(&point_at(from), &point_at(to)))
+    }
+
+    /// The euclidean distance from `p` to the nearest point of the segment.
+    pub fn distance_to(&self, p: &Point) -> f64 {
+        let (dx, dy) = self.p2.vec_dist(&self.p1);
+        let (px, py) = p.vec_dist(&self.p1);
+        let (dx, dy, px, py) = (dx as f64, dy as f64, px as f64, py as f64);
+        let length_sq = dx * dx + dy * dy;
+        let t = if length_sq == 0.0 {
+            0.0
+        } else {
+            ((px * dx + py * dy) / length_sq).clamp(0.0, 1.0)
+        };
+        (px - t * dx).hypot(py - t * dy)
+    }
+}
+
+/// Iterator over the points of a `Line`.
+struct Bresenham {
+    next: Option<Point>,
+    end: Point,
+    dx: isize,
+    dy: isize,
+    step: Point,
+    err: isize,
 }
 
+impl Iterator for Bresenham {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        let current = self.next?;
+        if current == self.end {
+            self.next = None;
+            return Some(current);
+        }
+        let mut next = current;
+        let e2 = 2 * self.err;
+        if e2 >= self.dy {
+            self.err += self.dy;
+            next.x += self.step.x;
+        }
+        if e2 <= self.dx {
+            self.err += self.dx;
+            next.y += self.step.y;
+        }
+        self.next = Some(next);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.next.map_or(0, |p| {
+            p.x.abs_diff(self.end.x).max(p.y.abs_diff(self.end.y)) + 1
+        });
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Bresenham {}
+
 #[cfg(test)]
 mod test {
-    use crate::{Line, Point};
+    use crate::{Bounds, Intersection, Line, LineKind, Point};
 
+    fn line(x1: isize, y1: isize, x2: isize, y2: isize) -> Line {
+        Line::new(&Point::new(x1, y1), &Point::new(x2, y2))
+    }
 
     #[test]
     fn test_line_on() {
@@ -38,4 +328,128 @@ mod test {
         assert!(l.on(&Point::new(1, 0)));
         assert!(!l.on(&Point::new(0, 1)));
     }
+
+    #[test]
+    fn test_line_kind() {
+        let origin = Point::new(0, 0);
+        let kind = |x, y| Line::new(&origin, &Point::new(x, y)).kind();
+        assert_eq!(kind(-3, 0), LineKind::Horizontal);
+        assert_eq!(kind(0, 2), LineKind::Vertical);
+        assert_eq!(kind(-2, 2), LineKind::Diagonal);
+        assert_eq!(kind(3, 1), LineKind::General);
+        assert_eq!(kind(0, 0), LineKind::Horizontal);
+        assert_eq!(Line::new(&origin, &Point::new(3, 4)).length(), 5.0);
+    }
+
+    #[test]
+    fn test_line_points() {
+        let diagonal = Line::new(&Point::new(3, 0), &Point::new(0, 3));
+        assert_eq!(
+            diagonal.points().collect::<Vec<_>>(),
+            vec![
+                Point::new(3, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 3)
+            ]
+        );
+        assert!(diagonal.points().all(|p| diagonal.on(&p)));
+        assert!(!diagonal.on(&Point::new(4, -1)));
+        let general = Line::new(&Point::new(0, 0), &Point::new(5, -2));
+        assert_eq!(
+            general.points().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, -1),
+                Point::new(3, -1),
+                Point::new(4, -2),
+                Point::new(5, -2)
+            ]
+        );
+        assert_eq!(general.points().len(), 6);
+        assert!(general.on(&Point::new(0, 0)));
+        assert!(!general.on(&Point::new(1, 0)));
+        let single = Line::new(&Point::new(1, 1), &Point::new(1, 1));
+        assert_eq!(single.points().collect::<Vec<_>>(), vec![Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn test_on_infinite() {
+        let l = line(0, 0, 2, 1);
+        assert!(l.on_infinite(&Point::new(-4, -2)));
+        assert!(!l.on_infinite(&Point::new(0, 5)));
+        assert!(!line(0, 0, 5, 0).on_infinite(&Point::new(0, 3)));
+        assert!(l.is_collinear(&line(4, 2, 6, 3)));
+        assert!(!l.is_collinear(&line(4, 2, 6, 4)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(
+            line(0, 0, 4, 4).intersection(&line(0, 4, 4, 0)),
+            Some(Intersection::Point(Point::new(2, 2)))
+        );
+        assert_eq!(line(0, 0, 4, 4).intersection(&line(5, 0, 5, 9)), None);
+        // The diagonals of a unit square cross at (0.5, 0.5).
+        let (a, b) = (line(0, 0, 1, 1), line(0, 1, 1, 0));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Intersection::OffGrid {
+                x: 1,
+                y: 1,
+                denom: 2
+            })
+        );
+        assert!(a.intersects(&b));
+        assert_eq!(
+            line(-3, 0, 0, 3).intersection(&line(-3, 2, 0, 0)),
+            Some(Intersection::OffGrid {
+                x: -9,
+                y: 6,
+                denom: 5
+            })
+        );
+        assert_eq!(
+            line(0, 0, 6, 0).intersection(&line(8, 0, 3, 0)),
+            Some(Intersection::Segment(line(3, 0, 6, 0)))
+        );
+        assert_eq!(
+            line(0, 0, 2, 2).intersection(&line(4, 4, 2, 2)),
+            Some(Intersection::Point(Point::new(2, 2)))
+        );
+        assert_eq!(line(0, 0, 2, 2).intersection(&line(3, 3, 5, 5)), None);
+        assert!(!line(0, 0, 0, 3).intersects(&line(1, 0, 1, 3)));
+    }
+
+    #[test]
+    fn test_clip() {
+        let bounds = Bounds::from_points([Point::new(0, 0), Point::new(4, 4)]).unwrap();
+        assert_eq!(line(-2, 2, 9, 2).clip(&bounds), Some(line(0, 2, 4, 2)));
+        assert_eq!(line(6, -1, -1, 6).clip(&bounds), Some(line(4, 1, 1, 4)));
+        assert_eq!(line(-3, -1, 9, 3).clip(&bounds), Some(line(0, 0, 4, 1)));
+        assert_eq!(line(-1, -1, 6, 1).clip(&bounds), Some(line(1, 0, 4, 0)));
+        assert_eq!(line(5, 0, 5, 4).clip(&bounds), None);
+        assert_eq!(line(1, 1, 1, 1).clip(&bounds), Some(line(1, 1, 1, 1)));
+        // The clipped end points are the first and last rasterised points
+        // inside the bounds, whichever way the line runs.
+        for (x1, y1) in [(-3, -2), (7, -1), (2, 9), (-5, 6), (1, 1)] {
+            for (x2, y2) in [(6, 1), (-2, 5), (3, -4), (8, 7), (0, 2), (1, 1)] {
+                let l = line(x1, y1, x2, y2);
+                let inside: Vec<Point> = l.points().filter(|p| bounds.contains(p)).collect();
+                let expected = inside
+                    .first()
+                    .map(|first| Line::new(first, inside.last().unwrap()));
+                assert_eq!(l.clip(&bounds), expected, "{:?}", l);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let l = line(0, 0, 4, 0);
+        assert_eq!(l.distance_to(&Point::new(2, 3)), 3.0);
+        assert_eq!(l.distance_to(&Point::new(7, 4)), 5.0);
+        assert_eq!(line(1, 1, 1, 1).distance_to(&Point::new(4, 5)), 5.0);
+    }
 }
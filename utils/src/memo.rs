@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache for recursive functions over a compact `Copy` state. The function
+/// passed to `get_or_compute` gets the cache back, so that it can recurse
+/// through it:
+///
+/// ```
+/// use utils::Memo;
+///
+/// fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get_or_compute(n, |memo| {
+///         if n < 2 { n } else { fib(n - 1, memo) + fib(n - 2, memo) }
+///     })
+/// }
+///
+/// assert_eq!(fib(90, &mut Memo::new()), 2880067194370816120);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Copy + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The cached value for `key`, computing and storing it first if needed.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K: Copy + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Memo;
+
+    #[test]
+    fn test_memo() {
+        let mut calls = 0;
+        let mut memo = Memo::new();
+        for _ in 0..3 {
+            let value = memo.get_or_compute((2, 'x'), |_| {
+                calls += 1;
+                42
+            });
+            assert_eq!(value, 42);
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(memo.get(&(2, 'x')), Some(&42));
+        assert_eq!(memo.len(), 1);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::Point;
+
+const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const MOORE: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// Which cells around a point count as its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    /// The four orthogonal neighbours.
+    VonNeumann,
+    /// The four orthogonal and the four diagonal neighbours.
+    Moore,
+    /// Any list of `(x, y)` offsets.
+    Custom(&'a [(isize, isize)]),
+}
+
+impl<'a> Neighbourhood<'a> {
+    pub fn offsets(&self) -> &'a [(isize, isize)] {
+        match self {
+            Neighbourhood::VonNeumann => &VON_NEUMANN,
+            Neighbourhood::Moore => &MOORE,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// What happens to neighbours that fall off the edge of a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// They are left out.
+    Bounded,
+    /// They wrap around to the opposite edge. On maps narrower than the
+    /// neighbourhood the same point can be yielded more than once.
+    Torus,
+}
+
+/// Iterator over the neighbours of a point on a `width` by `height` map.
+#[derive(Debug, Clone)]
+pub struct Neighbours<'a> {
+    center: Point,
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+    width: isize,
+    height: isize,
+    topology: Topology,
+}
+
+impl<'a> Neighbours<'a> {
+    pub fn new(
+        center: Point,
+        neighbourhood: Neighbourhood<'a>,
+        topology: Topology,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        Self {
+            center,
+            offsets: neighbourhood.offsets().iter(),
+            width: width as isize,
+            height: height as isize,
+            topology,
+        }
+    }
+}
+
+impl Iterator for Neighbours<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        for (xoffset, yoffset) in self.offsets.by_ref() {
+            let x = self.center.x + xoffset;
+            let y = self.center.y + yoffset;
+            match self.topology {
+                Topology::Bounded => {
+                    if x >= 0 && x < self.width && y >= 0 && y < self.height {
+                        return Some(Point::new(x, y));
+                    }
+                }
+                Topology::Torus => {
+                    if self.width > 0 && self.height > 0 {
+                        return Some(Point::new(
+                            x.rem_euclid(self.width),
+                            y.rem_euclid(self.height),
+                        ));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.offsets.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Neighbourhood, Neighbours, Point, Topology};
+
+    fn neighbours(p: Point, n: Neighbourhood, t: Topology) -> Vec<Point> {
+        Neighbours::new(p, n, t, 3, 2).collect()
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(
+            neighbours(
+                Point::new(0, 0),
+                Neighbourhood::VonNeumann,
+                Topology::Bounded
+            ),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            neighbours(Point::new(1, 0), Neighbourhood::Moore, Topology::Bounded),
+            vec![
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(1, 1),
+                Point::new(0, 1),
+                Point::new(0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_torus() {
+        assert_eq!(
+            neighbours(Point::new(0, 0), Neighbourhood::VonNeumann, Topology::Torus),
+            vec![
+                Point::new(0, 1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 0)
+            ]
+        );
+        assert_eq!(
+            Neighbours::new(
+                Point::new(0, 0),
+                Neighbourhood::Moore,
+                Topology::Torus,
+                0,
+                0
+            )
+            .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_custom() {
+        let knight = [(1, 2), (2, 1), (-1, 2)];
+        assert_eq!(
+            neighbours(
+                Point::new(0, 0),
+                Neighbourhood::Custom(&knight),
+                Topology::Bounded
+            ),
+            vec![Point::new(2, 1)]
+        );
+    }
+}
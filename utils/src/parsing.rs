@@ -1,83 +1,373 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::io::Cursor;
 use regex::Regex;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Input;
+
+/// Describes where and why parsing failed. Line and column are 1-based; the
+/// line is only known when the caller parsed a whole file and attached it
+/// with `on_line`, and there is no column when the input could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, text: &str, expected: &str) -> Self {
+        Self {
+            line: None,
+            column: Some(column),
+            text: text.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+
+    /// Reading `source` failed with `error` before there was text to parse.
+    pub fn unreadable(source: &str, expected: &str, error: &std::io::Error) -> Self {
+        Self {
+            line: None,
+            column: None,
+            text: source.to_owned(),
+            expected: format!("{} ({})", expected, error),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+        write!(f, "expected {}, found '{}'", self.expected, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+    Unsigned,
+    Signed,
+    Decimal,
+}
+
+/// Types that `numbers_on_line` and `num_between` can extract.
+///
+/// The kind decides what counts as a number in the text: integers are digits
+/// with an optional `-` sign, which unsigned types reject rather than skip,
+/// and decimal types also accept a fractional part. A `-` right after a digit
+/// separates two numbers, as in the range `2-4`, instead of being a sign.
+pub trait Number: FromStr {
+    const KIND: NumberKind;
+}
+
+macro_rules! impl_number {
+    ($kind:ident: $($t:ty),*) => {
+        $(impl Number for $t {
+            const KIND: NumberKind = NumberKind::$kind;
+        })*
+    };
+}
+
+impl_number!(Unsigned: u8, u16, u32, u64, u128, usize);
+impl_number!(Signed: i8, i16, i32, i64, i128, isize);
+impl_number!(Decimal: f32, f64);
+
+impl NumberKind {
+    /// Matches the numbers of this kind in a line, along with negative
+    /// numbers for unsigned types so that they are reported, not misread.
+    fn search_regex(&self) -> &'static Regex {
+        match self {
+            NumberKind::Unsigned => NumberKind::Signed.regex(),
+            _ => self.regex(),
+        }
+    }
+
+    /// Matches exactly the numbers of this kind.
+    fn regex(&self) -> &'static Regex {
+        lazy_static! {
+            static ref UNSIGNED: Regex = Regex::new(r"\d+").unwrap();
+            static ref SIGNED: Regex = Regex::new(r"-?\d+").unwrap();
+            static ref DECIMAL: Regex = Regex::new(r"-?\d+(?:\.\d+)?").unwrap();
+        };
+        match self {
+            NumberKind::Unsigned => &UNSIGNED,
+            NumberKind::Signed => &SIGNED,
+            NumberKind::Decimal => &DECIMAL,
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            NumberKind::Unsigned => "an unsigned integer",
+            NumberKind::Signed => "an integer",
+            NumberKind::Decimal => "a number",
+        }
+    }
+}
+
+/// Parses `text`, which started at 1-based `column` of its line. Text that has
+/// the shape of a number but still fails to parse is out of range for `T`.
+fn parse_number<T: Number>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse::<T>().map_err(|_| {
+        let is_number = T::KIND
+            .regex()
+            .find(text)
+            .is_some_and(|m| m.start() == 0 && m.end() == text.len());
+        let expected = if is_number {
+            format!("a value that fits in {}", std::any::type_name::<T>())
+        } else {
+            T::KIND.description().to_owned()
+        };
+        ParseError::new(column, text, &expected)
+    })
+}
+
+pub fn try_lines(
+    filename: &str,
+) -> Result<impl Iterator<Item = Result<String, ParseError>>, ParseError> {
+    Input::path(filename).try_lines()
+}
 
 pub fn lines(filename: &str) -> impl Iterator<Item = String> {
-    let r = BufReader::new(File::open(filename).expect("Failed to open file"));
-    r.lines().map(|elem| elem.expect("Couldn't read line"))
+    Input::path(filename).lines()
 }
 
 pub fn split_lines(filename: &str) -> impl Iterator<Item = Vec<String>> {
-    lines(filename)
-        .filter(|s| !s.is_empty())
-        .map(|s| s.split_whitespace().map(|str| String::from(str)).collect())
+    Input::path(filename).split_lines()
 }
 
 pub fn split_lines_sep(filename: &str, sep: char) -> impl Iterator<Item = Vec<String>> {
-    lines(filename)
-        .filter(|s| !s.is_empty())
-        .map(move |s| s.split(sep).map(|str| String::from(str)).collect())
+    Input::path(filename).split_lines_sep(sep)
 }
 
 pub fn char_lines(filename: &str) -> impl Iterator<Item = Vec<char>> {
-    lines(filename)
-        .filter(|s| !s.is_empty())
-        .map(|s| s.chars().collect())
+    Input::path(filename).char_lines()
 }
 
 pub fn string_lines(s: &str) -> impl Iterator<Item = String> + '_ {
-    let r = BufReader::new(Cursor::new(s)).lines();
-    r.map(|elem| elem.expect("Couldn't read line"))
+    Input::text(s).lines()
 }
 
 pub fn string_split_lines(s: &str) -> impl Iterator<Item = Vec<String>> + '_ {
-    println!("{}", s);
-    string_lines(s)
-        .filter(|s| !s.is_empty())
-        .map(|s| s.split_whitespace().map(|f| String::from(f)).collect())
+    Input::text(s).split_lines()
 }
 
 pub fn string_char_lines(s: &str) -> impl Iterator<Item = Vec<char>> + '_ {
-    string_lines(s)
-        .filter(|s| !s.is_empty())
-        .map(|s| s.chars().collect())
+    Input::text(s).char_lines()
 }
 
 pub fn string_split_lines_sep(s: &str, sep: char) -> impl Iterator<Item = Vec<String>> + '_ {
-    string_lines(s)
-        .filter(|s| !s.is_empty())
-        .map(move |s| s.split(sep).map(|str| String::from(str)).collect())
+    Input::text(s).split_lines_sep(sep)
 }
 
-pub fn str_between<'a>(line: &'a str, left: Option<&str>, right: Option<&str>) -> &'a str {
-    let left_offset = left
-        .map(|s| line.find(s).expect("Could not find left separator") + s.len())
-        .unwrap_or(0);
-    let right_offset = right
-        .map(|s| line.find(s).expect("Could not find right separator"))
-        .unwrap_or(line.len());
-    &line[left_offset..right_offset]
-}
-
-pub fn num_between(line: &str, left: Option<&str>, right: Option<&str>) -> i32 {
-    str_between(line, left, right).parse::<i32>().expect(
-        format!(
-            "Could not parse num between '{:?}' and '{:?}' in line {}",
-            left, right, line
-        )
-        .as_str(),
-    )
-}
-
-pub fn numbers_on_line(line: &str) -> Vec<isize> {
-    lazy_static! {
-        static ref RE: Regex = Regex::new(r"-?\d+").unwrap();
+/// Groups lines into blocks separated by one or more blank lines. Leading and
+/// trailing blank lines are ignored, so the input may or may not end with an
+/// empty line.
+pub struct Blocks<I> {
+    lines: I,
+    consumed: usize,
+    start: usize,
+}
+
+impl<I: Iterator<Item = String>> Blocks<I> {
+    pub fn new(lines: I) -> Self {
+        Self {
+            lines,
+            consumed: 0,
+            start: 0,
+        }
+    }
+
+    /// 1-based line number of the first line of the block returned last.
+    pub fn start_line(&self) -> usize {
+        self.start
+    }
+}
+
+impl<I: Iterator<Item = String>> Iterator for Blocks<I> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = Vec::new();
+        for line in self.lines.by_ref() {
+            self.consumed += 1;
+            if line.is_empty() {
+                if block.is_empty() {
+                    continue;
+                }
+                return Some(block);
+            }
+            if block.is_empty() {
+                self.start = self.consumed;
+            }
+            block.push(line);
+        }
+        if block.is_empty() {
+            None
+        } else {
+            Some(block)
+        }
+    }
+}
+
+pub fn blocks(filename: &str) -> Blocks<impl Iterator<Item = String>> {
+    Input::path(filename).blocks()
+}
+
+pub fn string_blocks(s: &str) -> Blocks<impl Iterator<Item = String> + '_> {
+    Input::text(s).blocks()
+}
+
+/// Parses every block of `lines` with `parser`. Line numbers in the parser's
+/// errors are relative to the block (1 being its first line) and are turned
+/// into line numbers of the whole input; errors without a line are reported
+/// on the first line of the block.
+pub fn blocks_with<T, F>(
+    lines: impl Iterator<Item = String>,
+    mut parser: F,
+) -> impl Iterator<Item = Result<T, ParseError>>
+where
+    F: FnMut(&[String]) -> Result<T, ParseError>,
+{
+    let mut blocks = Blocks::new(lines);
+    std::iter::from_fn(move || {
+        let block = blocks.next()?;
+        let start = blocks.start_line();
+        Some(parser(&block).map_err(|e| {
+            let line = start + e.line.unwrap_or(1) - 1;
+            e.on_line(line)
+        }))
+    })
+}
+
+/// Parses every non-empty line of `lines` with `parser`, attaching the
+/// 1-based line number to the first error.
+pub fn lines_with<T, F>(
+    lines: impl Iterator<Item = String>,
+    mut parser: F,
+) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(idx, l)| parser(&l).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+/// Parses every non-empty line of `lines` as a `T`.
+pub fn parse_lines<T>(lines: impl Iterator<Item = String>) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    lines_with(lines, str::parse)
+}
+
+/// Runs the nom `parser` over all of `line`. Where it stopped is reported as
+/// a `ParseError` that expected `expected`, or the end of line if the parser
+/// succeeded without consuming everything.
+pub fn nom_line<'a, T>(
+    line: &'a str,
+    mut parser: impl FnMut(&'a str) -> nom::IResult<&'a str, T>,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let column = |rest: &str| line.len() - rest.len() + 1;
+    match parser(line) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(ParseError::new(column(rest), rest, "the end of line")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(ParseError::new(column(e.input), e.input, expected))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(column(""), "", expected)),
+    }
+}
+
+fn offsets_between(
+    line: &str,
+    left: Option<&str>,
+    right: Option<&str>,
+) -> Result<(usize, usize), ParseError> {
+    let left_offset = match left {
+        Some(s) => {
+            line.find(s)
+                .ok_or_else(|| ParseError::new(1, line, &format!("'{}'", s)))?
+                + s.len()
+        }
+        None => 0,
     };
-    let mut result = Vec::new();
-    for m in RE.captures_iter(line) {
-        result.push(m[0].parse::<isize>().unwrap());
+    let rest = &line[left_offset..];
+    let right_offset = match right {
+        Some(s) => {
+            left_offset
+                + rest
+                    .find(s)
+                    .ok_or_else(|| ParseError::new(left_offset + 1, rest, &format!("'{}'", s)))?
+        }
+        None => line.len(),
     };
-    result
+    Ok((left_offset, right_offset))
+}
+
+/// Text between the first occurrence of `left` and the next occurrence of
+/// `right` after it. A missing separator stands for the start or end of line.
+pub fn try_str_between<'a>(
+    line: &'a str,
+    left: Option<&str>,
+    right: Option<&str>,
+) -> Result<&'a str, ParseError> {
+    let (start, end) = offsets_between(line, left, right)?;
+    Ok(&line[start..end])
+}
+
+pub fn str_between<'a>(line: &'a str, left: Option<&str>, right: Option<&str>) -> &'a str {
+    try_str_between(line, left, right).unwrap_or_else(|e| panic!("Could not find separator: {}", e))
+}
+
+pub fn try_num_between<T: Number>(
+    line: &str,
+    left: Option<&str>,
+    right: Option<&str>,
+) -> Result<T, ParseError> {
+    let (start, end) = offsets_between(line, left, right)?;
+    parse_number(&line[start..end], start + 1)
+}
+
+pub fn num_between<T: Number>(line: &str, left: Option<&str>, right: Option<&str>) -> T {
+    try_num_between(line, left, right)
+        .unwrap_or_else(|e| panic!("Could not parse num in line '{}': {}", line, e))
+}
+
+pub fn try_numbers_on_line<T: Number>(line: &str) -> Result<Vec<T>, ParseError> {
+    T::KIND
+        .search_regex()
+        .find_iter(line)
+        .map(|m| {
+            let after_digit = line[..m.start()].ends_with(|c: char| c.is_ascii_digit());
+            let start = if after_digit && m.as_str().starts_with('-') {
+                m.start() + 1
+            } else {
+                m.start()
+            };
+            parse_number(&line[start..m.end()], start + 1)
+        })
+        .collect()
+}
+
+pub fn numbers_on_line<T: Number>(line: &str) -> Vec<T> {
+    try_numbers_on_line(line)
+        .unwrap_or_else(|e| panic!("Could not parse numbers in line '{}': {}", line, e))
 }
@@ -1,112 +1,303 @@
-use std::cmp::max;
-use std::cmp::min;
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::ops::Add;
 use std::ops::AddAssign;
+use std::ops::Mul;
+use std::ops::Neg;
 use std::ops::Sub;
 use std::ops::SubAssign;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
+use crate::Direction;
+
+/// Integer types that can be the coordinates of a `Point` or `Point3`.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// The distance between the two values, which always fits in a `u64`.
+    fn abs_diff(self, other: Self) -> u64;
 }
 
-impl Point {
-    pub fn new(x: isize, y: isize) -> Self {
+macro_rules! impl_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn abs_diff(self, other: Self) -> u64 {
+                <$t>::abs_diff(self, other) as u64
+            }
+        }
+    )*};
+}
+
+impl_coord!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// `value` moved by -1, 0 or 1, or `None` if that does not fit in `T`.
+pub(crate) fn offset<T: Coord>(value: T, delta: i8) -> Option<T> {
+    match delta {
+        -1 => value.checked_sub(T::ONE),
+        0 => Some(value),
+        1 => value.checked_add(T::ONE),
+        _ => panic!("Can only offset by -1, 0 or 1, not {}", delta),
+    }
+}
+
+/// The values from `from` to `to`, both included, in increasing order.
+pub(crate) fn inclusive_range<T: Coord>(from: T, to: T) -> impl Iterator<Item = T> {
+    let (low, high) = (from.min(to), from.max(to));
+    std::iter::successors(Some(low), move |&v| {
+        if v < high {
+            v.checked_add(T::ONE)
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
 
     pub fn is_adjacent(&self, other: &Self) -> bool {
-        let (xdist, ydist) = self.vec_dist(other);
-        xdist <= 1 && xdist >= -1 && ydist <= 1 && ydist >= -1
+        self.x.abs_diff(other.x) <= 1 && self.y.abs_diff(other.y) <= 1
     }
 
-    pub fn vec_dist(&self, other: &Self) -> (i32, i32) {
-        (
-            (self.x as i32) - (other.x as i32),
-            (self.y as i32) - (other.y as i32),
-        )
+    /// `self - other` per axis, panicking if that does not fit in `T`.
+    pub fn vec_dist(&self, other: &Self) -> (T, T) {
+        let d = self
+            .checked_sub(other)
+            .unwrap_or_else(|| panic!("{:?} - {:?} overflows", self, other));
+        (d.x, d.y)
     }
 
     /*
-    Taxicab distance between two points.
+    Taxicab distance between two points, panicking if it does not fit in a u64.
     */
-    pub fn dist(&self, other: &Point) -> u32 {
-        (((self.x as isize) - (other.x as isize)).abs()
-            + ((self.y as isize) - (other.y as isize)).abs()) as u32
+    pub fn dist(&self, other: &Self) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .checked_add(self.y.abs_diff(other.y))
+            .unwrap_or_else(|| panic!("Distance from {:?} to {:?} overflows", self, other))
+    }
+
+    pub fn adjust(&mut self, xdiff: T, ydiff: T) {
+        *self = self
+            .checked_add(&Point::new(xdiff, ydiff))
+            .unwrap_or_else(|| panic!("{:?} + ({:?}, {:?}) overflows", self, xdiff, ydiff));
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
     }
 
-    pub fn adjust(&mut self, xdiff: i32, ydiff: i32) {
-        self.x = ((self.x as i32) + xdiff) as isize;
-        self.y = ((self.y as i32) + ydiff) as isize;
+    pub fn checked_mul(&self, factor: T) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
     }
 
     pub fn orthogonal_range<'a>(
-        self: &'a Point,
-        p2: &'a Point,
-    ) -> Box<dyn Iterator<Item = Point> + 'a> {
+        self: &'a Point<T>,
+        p2: &'a Point<T>,
+    ) -> Box<dyn Iterator<Item = Point<T>> + 'a> {
         if self.x != p2.x && self.y != p2.y {
             panic!("Can only generate orthogonal ranges!");
         };
         if self.x == p2.x {
-            Box::new((min(self.y, p2.y)..max(self.y, p2.y) + 1).map(|y| Point::new(self.x, y)))
+            Box::new(inclusive_range(self.y, p2.y).map(|y| Point::new(self.x, y)))
         } else {
-            Box::new((min(self.x, p2.x)..max(self.x, p2.x) + 1).map(|x| Point::new(x, self.y)))
+            Box::new(inclusive_range(self.x, p2.x).map(|x| Point::new(x, self.y)))
         }
     }
 
-    pub fn points_at_dist<'a>(&'a self, d: isize) -> Box<dyn Iterator<Item = Point> + 'a> {
-        Box::new((0..d + 1)
-            .flat_map(move |offset| {
-                vec![
-                    Point::new(self.x + offset, self.y + (d - offset)),
-                    Point::new(self.x - offset, self.y - (d - offset)),
-                    Point::new(self.x + offset, self.y - (d - offset)),
-                    Point::new(self.x - offset, self.y + (d - offset)),
-                ]
-            }))
+    /// The points at taxicab distance `d`, leaving out those with
+    /// coordinates that do not fit in `T`.
+    pub fn points_at_dist<'a>(&'a self, d: T) -> Box<dyn Iterator<Item = Point<T>> + 'a> {
+        Box::new(inclusive_range(T::ZERO, d).flat_map(move |offset| {
+            let rest = d - offset;
+            [
+                (self.x.checked_add(offset), self.y.checked_add(rest)),
+                (self.x.checked_sub(offset), self.y.checked_sub(rest)),
+                (self.x.checked_add(offset), self.y.checked_sub(rest)),
+                (self.x.checked_sub(offset), self.y.checked_add(rest)),
+            ]
+            .into_iter()
+            .filter_map(|(x, y)| Some(Point::new(x?, y?)))
+        }))
     }
 }
 
-impl From<(isize, isize)> for Point {
-    fn from(value: (isize, isize)) -> Self {
-        Point::new(value.0, value.1)
+impl<T: Coord + Neg<Output = T>> Point<T> {
+    /// The point turned a quarter clockwise about `origin`, with `y`
+    /// growing downwards as in a `Grid`. Panics if it does not fit in `T`.
+    pub fn rotate_cw(&self, origin: &Self) -> Self {
+        self.rotated(origin, |d| Some(Point::new(T::ZERO.checked_sub(d.y)?, d.x)))
+    }
+
+    /// The point turned a quarter anticlockwise about `origin`. Panics if it
+    /// does not fit in `T`.
+    pub fn rotate_ccw(&self, origin: &Self) -> Self {
+        self.rotated(origin, |d| Some(Point::new(d.y, T::ZERO.checked_sub(d.x)?)))
+    }
+
+    fn rotated(&self, origin: &Self, turn: impl Fn(Self) -> Option<Self>) -> Self {
+        self.checked_sub(origin)
+            .and_then(turn)
+            .and_then(|d| origin.checked_add(&d))
+            .unwrap_or_else(|| panic!("{:?} turned about {:?} overflows", self, origin))
     }
 }
 
-impl Add for Point {
-    type Output = Self;
+impl Point {
+    /// The point `n` steps away in direction `dir`, or `None` if it does
+    /// not fit in an `isize`.
+    pub fn checked_step(&self, dir: Direction, n: isize) -> Option<Self> {
+        let v = dir.offset();
+        self.checked_add(&Point::new(v.x.checked_mul(n)?, v.y.checked_mul(n)?))
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
+    /// Like `checked_step`, but panics if the point does not fit.
+    pub fn step(&self, dir: Direction, n: isize) -> Self {
+        self.checked_step(dir, n)
+            .unwrap_or_else(|| panic!("{:?} + {} steps {:?} overflows", self, n, dir))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from(value: (T, T)) -> Self {
         Point {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
+            x: value.0,
+            y: value.1,
         }
     }
 }
 
-impl Sub for Point {
+/// Adds the points, panicking if the sum does not fit in `T`.
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs)
+            .unwrap_or_else(|| panic!("{:?} + {:?} overflows", self, rhs))
+    }
+}
+
+/// Subtracts the points, panicking if the difference does not fit in `T`.
+impl<T: Coord> Sub for Point<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Point {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+        self.checked_sub(&rhs)
+            .unwrap_or_else(|| panic!("{:?} - {:?} overflows", self, rhs))
+    }
+}
+
+/// Scales the point, panicking if it does not fit in `T`.
+impl<T: Coord> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| panic!("{:?} * {:?} overflows", self, rhs))
     }
 }
 
-impl AddAssign for Point {
+/// Mirrors the point through the origin, panicking if that does not fit in
+/// `T`, as for `T::MIN`.
+impl<T: Coord + Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point::new(T::ZERO, T::ZERO)
+            .checked_sub(&self)
+            .unwrap_or_else(|| panic!("-{:?} overflows", self))
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
     fn add_assign(&mut self, rhs: Self) {
-        self.x = self.x + rhs.x;
-        self.y = self.y + rhs.y;
+        *self = *self + rhs;
     }
 }
 
-impl SubAssign for Point {
+impl<T: Coord> SubAssign for Point<T> {
     fn sub_assign(&mut self, rhs: Self) {
-        self.x = self.x - rhs.x;
-        self.y = self.y - rhs.y;
+        *self = *self - rhs;
+    }
+}
+
+/// The smallest rectangle, edges included, that holds a set of points.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds<T = isize> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coord> Bounds<T> {
+    pub fn new(p: Point<T>) -> Self {
+        Bounds { min: p, max: p }
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(&p);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds to hold `p`.
+    pub fn include(&mut self, p: &Point<T>) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
     }
 }
 
@@ -114,7 +305,7 @@ impl SubAssign for Point {
 mod test {
     use std::collections::HashSet;
 
-    use crate::Point;
+    use crate::{Bounds, Point};
 
     #[test]
     fn test_adjacent() {
@@ -175,7 +366,9 @@ mod test {
     #[test]
     fn test_points_at_dist() {
         assert_eq!(
-            Point::new(0, 0).points_at_dist(1).collect::<HashSet<Point>>(),
+            Point::new(0, 0)
+                .points_at_dist(1)
+                .collect::<HashSet<Point>>(),
             HashSet::from([
                 Point::new(-1, 0),
                 Point::new(1, 0),
@@ -184,7 +377,9 @@ mod test {
             ])
         );
         assert_eq!(
-            Point::new(0, 0).points_at_dist(2).collect::<HashSet<Point>>(),
+            Point::new(0, 0)
+                .points_at_dist(2)
+                .collect::<HashSet<Point>>(),
             HashSet::from([
                 Point::new(-2, 0),
                 Point::new(-1, -1),
@@ -197,4 +392,86 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn test_generic() {
+        let p: Point<u8> = Point::new(0, 1);
+        assert_eq!(
+            p.points_at_dist(1).collect::<HashSet<_>>(),
+            HashSet::from([Point::new(0, 0), Point::new(1, 1), Point::new(0, 2)])
+        );
+        assert_eq!(p.checked_sub(&Point::new(1, 0)), None);
+        assert_eq!(
+            Point::new(250u8, 0).checked_add(&Point::new(5, 1)),
+            Some(Point::new(255, 1))
+        );
+        let big = Point::new(4_000_000_000_000i64, 0);
+        assert_eq!(big.vec_dist(&Point::new(0, 3)), (4_000_000_000_000, -3));
+        assert_eq!(big.dist(&Point::new(0, 3)), 4_000_000_000_003);
+        let mut q = Point::new(i64::MAX - 1, 0);
+        q.adjust(1, -1);
+        assert_eq!(q, Point::new(i64::MAX, -1));
+        assert!(Point::new(0u32, 0).is_adjacent(&Point::new(1, 1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_adjust_overflow() {
+        Point::new(i8::MAX, 0).adjust(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_add_overflow() {
+        let _ = Point::new(i8::MAX, 0) + Point::new(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_sub_assign_overflow() {
+        let mut p = Point::new(0u8, 0);
+        p -= Point::new(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_neg_overflow() {
+        let _ = -Point::new(0, i8::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_dist_overflow() {
+        Point::new(i64::MIN, i64::MIN).dist(&Point::new(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds =
+            Bounds::from_points([Point::new(3, -1), Point::new(-2, 4), Point::new(0, 0)]).unwrap();
+        assert_eq!(
+            (bounds.min, bounds.max),
+            (Point::new(-2, -1), Point::new(3, 4))
+        );
+        assert!(bounds.contains(&Point::new(3, 4)));
+        assert!(!bounds.contains(&Point::new(4, 0)));
+        assert_eq!(Bounds::<isize>::from_points([]), None);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let origin = Point::new(1, 1);
+        let p = Point::new(3, 1);
+        assert_eq!(p.rotate_cw(&origin), Point::new(1, 3));
+        assert_eq!(p.rotate_ccw(&origin), Point::new(1, -1));
+        assert_eq!(p.rotate_cw(&origin).rotate_ccw(&origin), p);
+        assert_eq!(
+            Point::new(0, -1).rotate_cw(&Point::new(0, 0)),
+            Point::new(1, 0)
+        );
+        assert_eq!(p * 3, Point::new(9, 3));
+        assert_eq!(-p, Point::new(-3, -1));
+        assert_eq!(Point::new(2i8, 5) * 2, Point::new(4, 10));
+        assert_eq!(Point::new(2i8, 100).checked_mul(2), None);
+    }
 }
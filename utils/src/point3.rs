@@ -0,0 +1,212 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::point::{offset, Coord};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Taxicab distance between two points, panicking if it does not fit in
+    /// a `u64`.
+    pub fn dist(&self, other: &Self) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .checked_add(self.y.abs_diff(other.y))
+            .and_then(|d| d.checked_add(self.z.abs_diff(other.z)))
+            .unwrap_or_else(|| panic!("Distance from {:?} to {:?} overflows", self, other))
+    }
+
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        self.x.abs_diff(other.x) <= 1
+            && self.y.abs_diff(other.y) <= 1
+            && self.z.abs_diff(other.z) <= 1
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Point3::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Point3::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+            self.z.checked_sub(other.z)?,
+        ))
+    }
+
+    fn offsets(self, max_changed: usize) -> impl Iterator<Item = Self> {
+        const DELTAS: [i8; 3] = [-1, 0, 1];
+        DELTAS
+            .iter()
+            .flat_map(|&dx| {
+                DELTAS
+                    .iter()
+                    .flat_map(move |&dy| DELTAS.map(|dz| (dx, dy, dz)))
+            })
+            .filter(move |&(dx, dy, dz)| {
+                let changed = [dx, dy, dz].iter().filter(|&&d| d != 0).count();
+                changed > 0 && changed <= max_changed
+            })
+            .filter_map(move |(dx, dy, dz)| {
+                Some(Point3::new(
+                    offset(self.x, dx)?,
+                    offset(self.y, dy)?,
+                    offset(self.z, dz)?,
+                ))
+            })
+    }
+
+    /// The six points sharing a face with this one, leaving out those with
+    /// coordinates that do not fit in `T`.
+    pub fn neighbours6(&self) -> impl Iterator<Item = Self> {
+        self.offsets(1)
+    }
+
+    /// The 26 points sharing a face, an edge or a corner with this one,
+    /// leaving out those with coordinates that do not fit in `T`.
+    pub fn neighbours26(&self) -> impl Iterator<Item = Self> {
+        self.offsets(3)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from(value: (T, T, T)) -> Self {
+        Point3 {
+            x: value.0,
+            y: value.1,
+            z: value.2,
+        }
+    }
+}
+
+/// Adds the points, panicking if the sum does not fit in `T`.
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs)
+            .unwrap_or_else(|| panic!("{:?} + {:?} overflows", self, rhs))
+    }
+}
+
+/// Subtracts the points, panicking if the difference does not fit in `T`.
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs)
+            .unwrap_or_else(|| panic!("{:?} - {:?} overflows", self, rhs))
+    }
+}
+
+impl<T: Coord> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// The smallest box, faces included, that holds a set of points.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds3<T = isize> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Coord> Bounds3<T> {
+    pub fn new(p: Point3<T>) -> Self {
+        Bounds3 { min: p, max: p }
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(&p);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds to hold `p`.
+    pub fn include(&mut self, p: &Point3<T>) {
+        self.min = Point3::new(
+            self.min.x.min(p.x),
+            self.min.y.min(p.y),
+            self.min.z.min(p.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(p.x),
+            self.max.y.max(p.y),
+            self.max.z.max(p.z),
+        );
+    }
+
+    pub fn contains(&self, p: &Point3<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{Bounds3, Point3};
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point3::new(1, 2, 3);
+        p += Point3::new(1, 1, 1);
+        assert_eq!(p - Point3::new(2, 3, 4), Point3::new(0, 0, 0));
+        assert_eq!(p.dist(&Point3::new(0, 0, 0)), 9);
+        assert!(p.is_adjacent(&Point3::new(3, 4, 3)));
+        assert_eq!(
+            Point3::new(0u8, 0, 0).checked_sub(&Point3::new(0, 0, 1)),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_add_overflow() {
+        let _ = Point3::new(0, 0, u8::MAX) + Point3::new(0, 0, 1);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Point3::new(0, 0, 0);
+        let faces: HashSet<_> = origin.neighbours6().collect();
+        assert_eq!(faces.len(), 6);
+        assert!(faces.iter().all(|p| p.dist(&origin) == 1));
+        assert_eq!(origin.neighbours26().count(), 26);
+        assert!(origin.neighbours26().all(|p| p.is_adjacent(&origin)));
+        assert_eq!(Point3::new(0u32, 0, 0).neighbours6().count(), 3);
+        assert_eq!(Point3::new(0u32, 0, 0).neighbours26().count(), 7);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds3::from_points([Point3::new(1, 5, -2), Point3::new(3, 0, 0)]).unwrap();
+        assert_eq!(bounds.min, Point3::new(1, 0, -2));
+        assert!(bounds.contains(&Point3::new(2, 2, -1)));
+        assert!(!bounds.contains(&Point3::new(2, 2, 1)));
+    }
+}
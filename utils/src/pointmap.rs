@@ -1,41 +1,54 @@
 use std::collections::HashSet;
 
 use crate::point::Point;
+use crate::{Direction, Grid, Neighbourhood, Neighbours, Topology};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum LookDirection {
-    ToNorth,
-    ToEast,
-    ToSouth,
-    ToWest,
-}
-
-impl LookDirection {
-    pub fn all() -> [LookDirection; 4] {
-        static ALL_DIRECTIONS: [LookDirection; 4] = [
-            LookDirection::ToNorth,
-            LookDirection::ToEast,
-            LookDirection::ToSouth,
-            LookDirection::ToWest,
-        ];
-        ALL_DIRECTIONS
-    }
-}
+pub type PointMap = Grid<u32>;
 
-pub type PointMap = Vec<Vec<u32>>;
 pub trait PointMappable {
     fn load(l: impl Iterator<Item = String>) -> Self;
     fn load_with(l: impl Iterator<Item = String>, transform: Box<dyn Fn(char) -> u32>) -> Self;
     fn at(&self, p: &Point) -> u32;
     fn sizex(&self) -> usize;
     fn sizey(&self) -> usize;
-    fn points_in_direction(&self, p: &Point, d: &LookDirection) -> Vec<Point>;
     fn adjacent_points(&self, p: &Point) -> HashSet<Point>;
+    /// All points of the map, row by row.
     fn all_points(&self) -> Box<dyn Iterator<Item = Point> + '_>;
     fn print(&self);
+
+    /// The points between `p` and the edge of the map in direction `d`,
+    /// leaving out `p` itself, from the lowest coordinate to the highest: so
+    /// nearest first looking east or south, and farthest first otherwise.
+    /// Panics if `d` is diagonal, which has no such order.
+    fn points_in_direction(&self, p: &Point, d: &Direction) -> Vec<Point> {
+        assert!(
+            Direction::CARDINAL.contains(d),
+            "Points in direction {:?} have no order, only cardinal directions do",
+            d
+        );
+        let (sizex, sizey) = (self.sizex() as isize, self.sizey() as isize);
+        let mut points: Vec<Point> = (1..)
+            .map_while(|n| p.checked_step(*d, n))
+            .take_while(|q| (0..sizex).contains(&q.x) && (0..sizey).contains(&q.y))
+            .collect();
+        if matches!(d, Direction::North | Direction::West) {
+            points.reverse();
+        }
+        points
+    }
+
+    /// Neighbours of `p` without allocating, unlike `adjacent_points`.
+    fn neighbours<'a>(
+        &self,
+        p: &Point,
+        neighbourhood: Neighbourhood<'a>,
+        topology: Topology,
+    ) -> Neighbours<'a> {
+        Neighbours::new(*p, neighbourhood, topology, self.sizex(), self.sizey())
+    }
 }
 
-impl PointMappable for PointMap {
+impl PointMappable for Vec<Vec<u32>> {
     fn load(l: impl Iterator<Item = String>) -> Self {
         Self::load_with(l, Box::new(|c: char| c.to_digit(10).unwrap()))
     }
@@ -55,7 +68,7 @@ impl PointMappable for PointMap {
     }
 
     fn sizex(&self) -> usize {
-        self[0].len()
+        self.first().map_or(0, Vec::len)
     }
 
     fn sizey(&self) -> usize {
@@ -63,37 +76,14 @@ impl PointMappable for PointMap {
     }
 
     fn adjacent_points(&self, p: &Point) -> HashSet<Point> {
-        let mut result = HashSet::new();
-        for (xoffset, yoffset) in [(-1, 0), (1, 0), (0, 1), (0, -1)] {
-            let new_point = *p + Point::new(xoffset, yoffset);
-            if new_point.x >= 0
-                && new_point.x < (self.sizex().try_into().unwrap())
-                && new_point.y >= 0
-                && new_point.y < (self.sizey().try_into().unwrap())
-            {
-                result.insert(new_point);
-            }
-        }
-        result
-    }
-
-    fn points_in_direction(&self, p: &Point, d: &LookDirection) -> Vec<Point> {
-        match *d {
-            LookDirection::ToNorth => (0..p.y).map(|y| Point::new(p.x, y)).collect(),
-            LookDirection::ToEast => (p.x + 1..self.sizex() as isize)
-                .map(|x| Point::new(x as isize, p.y))
-                .collect(),
-            LookDirection::ToSouth => (p.y + 1..self.sizey() as isize)
-                .map(|y| Point::new(p.x, y))
-                .collect(),
-            LookDirection::ToWest => (0..p.x).map(|x| Point::new(x, p.y)).collect(),
-        }
+        self.neighbours(p, Neighbourhood::VonNeumann, Topology::Bounded)
+            .collect()
     }
 
     fn all_points(&self) -> Box<dyn Iterator<Item = Point> + '_> {
         Box::new(
-            (0..self.sizex())
-                .flat_map(|x| (0..self.sizey()).map(move |y| Point::new(x as isize, y as isize))),
+            (0..self.sizey())
+                .flat_map(|y| (0..self.sizex()).map(move |x| Point::new(x as isize, y as isize))),
         )
     }
 
@@ -101,7 +91,65 @@ impl PointMappable for PointMap {
         println!(
             "{}",
             self.iter()
-                .map(|r| r.iter().map(|c| format!("{:3}", c)).collect::<Vec<String>>().join(""))
+                .map(|r| r
+                    .iter()
+                    .map(|c| format!("{:3}", c))
+                    .collect::<Vec<String>>()
+                    .join(""))
+                .collect::<Vec<String>>()
+                .join("\n")
+        );
+    }
+}
+
+impl PointMappable for Grid<u32> {
+    fn load(l: impl Iterator<Item = String>) -> Self {
+        Grid::from_digits(l).unwrap_or_else(|e| panic!("Invalid map: {}", e))
+    }
+
+    fn load_with(l: impl Iterator<Item = String>, transform: Box<dyn Fn(char) -> u32>) -> Self {
+        Grid::from_chars(l, transform).unwrap_or_else(|e| panic!("Invalid map: {}", e))
+    }
+
+    fn at(&self, p: &Point) -> u32 {
+        self[p]
+    }
+
+    fn sizex(&self) -> usize {
+        self.width()
+    }
+
+    fn sizey(&self) -> usize {
+        self.height()
+    }
+
+    fn adjacent_points(&self, p: &Point) -> HashSet<Point> {
+        Grid::neighbours(self, p, Neighbourhood::VonNeumann, Topology::Bounded).collect()
+    }
+
+    /// The same as `Grid::neighbours`, whichever of the two a call picks.
+    fn neighbours<'a>(
+        &self,
+        p: &Point,
+        neighbourhood: Neighbourhood<'a>,
+        topology: Topology,
+    ) -> Neighbours<'a> {
+        Grid::neighbours(self, p, neighbourhood, topology)
+    }
+
+    fn all_points(&self) -> Box<dyn Iterator<Item = Point> + '_> {
+        Box::new(self.points())
+    }
+
+    fn print(&self) {
+        println!(
+            "{}",
+            self.rows()
+                .map(|r| r
+                    .iter()
+                    .map(|c| format!("{:3}", c))
+                    .collect::<Vec<String>>()
+                    .join(""))
                 .collect::<Vec<String>>()
                 .join("\n")
         );
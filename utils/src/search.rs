@@ -0,0 +1,344 @@
+//! Shortest paths over graphs given as a successor function, so the nodes can
+//! be anything hashable: points on a map, states of a puzzle, and so on.
+//!
+//! The searches that look for a goal take either a predicate or a set of
+//! goal nodes and return `None` when no goal can be reached.
+
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Path costs: the `Default` value must be the zero cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// The nodes a search is looking for.
+pub trait Goal<N> {
+    fn is_goal(&mut self, node: &N) -> bool;
+}
+
+impl<N, F: FnMut(&N) -> bool> Goal<N> for F {
+    fn is_goal(&mut self, node: &N) -> bool {
+        self(node)
+    }
+}
+
+impl<N: Eq + Hash> Goal<N> for &HashSet<N> {
+    fn is_goal(&mut self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+/// Every node a search reached with its final distance from the start, and
+/// the node each was reached from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reached<N: Eq + Hash, C> {
+    pub distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> Reached<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The nodes from the start up to and including `node`.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A shortest path to the first goal that was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N: Eq + Hash, C> {
+    pub goal: N,
+    pub distance: C,
+    /// The nodes from the start up to and including the goal.
+    pub path: Vec<N>,
+    /// Distances of all nodes whose distance was final when the goal was found.
+    pub distances: HashMap<N, C>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> Path<N, C> {
+    fn new(reached: Reached<N, C>, goal: N) -> Self {
+        Self {
+            distance: reached.distances[&goal],
+            path: reached.path_to(&goal).expect("Goal was reached"),
+            distances: reached.distances,
+            goal,
+        }
+    }
+}
+
+/// Heap entry ordered so that the `BinaryHeap` pops the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn breadth_first<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl Goal<N>,
+) -> (Reached<N, usize>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new();
+    reached.distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal.is_goal(&node) {
+            return (reached, Some(node));
+        }
+        let distance = reached.distances[&node] + 1;
+        for next in successors(&node) {
+            if !reached.distances.contains_key(&next) {
+                reached.distances.insert(next.clone(), distance);
+                reached.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    (reached, None)
+}
+
+fn best_first<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl Goal<N>,
+) -> (Reached<N, C>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Reached::new();
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if reached.distances.contains_key(&node) {
+            continue;
+        }
+        reached.distances.insert(node.clone(), cost);
+        if let Some(parent) = parents.remove(&node) {
+            reached.parents.insert(node.clone(), parent);
+        }
+        if goal.is_goal(&node) {
+            return (reached, Some(node));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if reached.distances.contains_key(&next)
+                || best.get(&next).is_some_and(|&c| c <= next_cost)
+            {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    (reached, None)
+}
+
+/// Shortest path when every step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl Goal<N>,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let (reached, found) = breadth_first(start, successors, goal);
+    found.map(|goal| Path::new(reached, goal))
+}
+
+/// Distances from `start` to every reachable node when every step costs 1.
+pub fn bfs_all<N, I>(start: N, successors: impl FnMut(&N) -> I) -> Reached<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(start, successors, |_: &N| false).0
+}
+
+/// Shortest path when `successors` yields each next node with the
+/// non-negative cost of the step to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl Goal<N>,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, found) = best_first(start, successors, |_| C::default(), goal);
+    found.map(|goal| Path::new(reached, goal))
+}
+
+/// Distances from `start` to every reachable node, with step costs as for
+/// `dijkstra`.
+pub fn dijkstra_all<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> Reached<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), |_: &N| false).0
+}
+
+/// Shortest path like `dijkstra`, guided by `heuristic`, which estimates the
+/// remaining cost to a goal. The path is only guaranteed to be the shortest
+/// when the estimate never exceeds the cost of a step plus the estimate
+/// after it.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl Goal<N>,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, found) = best_first(start, successors, heuristic, goal);
+    found.map(|goal| Path::new(reached, goal))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::search::{astar, bfs, bfs_all, dijkstra, dijkstra_all};
+    use crate::{Grid, Neighbourhood, Point, Topology};
+
+    fn maze() -> Grid<char> {
+        Grid::from_rows(
+            ["..#....", ".##.##.", "...#...", "#.....#"].map(|row| row.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    fn open_neighbours(maze: &Grid<char>, p: &Point) -> Vec<Point> {
+        maze.neighbours(p, Neighbourhood::VonNeumann, Topology::Bounded)
+            .filter(|n| maze[n] == '.')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let goal = Point::new(6, 0);
+        let path = bfs(
+            Point::new(0, 0),
+            |p| open_neighbours(&maze, p),
+            |p: &Point| *p == goal,
+        )
+        .unwrap();
+        assert_eq!(path.distance, 12);
+        assert_eq!(path.path.len(), 13);
+        assert_eq!(path.path.first(), Some(&Point::new(0, 0)));
+        assert!(path.path.windows(2).all(|w| w[0].is_adjacent(&w[1])));
+        assert_eq!(path.distances[&Point::new(1, 3)], 4);
+        let walled = HashSet::from([Point::new(0, 3)]);
+        assert!(bfs(Point::new(0, 0), |p| open_neighbours(&maze, p), &walled).is_none());
+        assert_eq!(
+            bfs_all(Point::new(0, 0), |p| open_neighbours(&maze, p))
+                .distances
+                .len(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going through 'b' costs 1 + 1, the direct edge to 'c' costs 5.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        };
+        let path = dijkstra('a', edges, |n: &char| *n == 'd').unwrap();
+        assert_eq!((path.distance, path.path), (4, vec!['a', 'b', 'c', 'd']));
+        let reached = dijkstra_all('a', edges);
+        assert_eq!(reached.distance(&'c'), Some(2));
+        assert_eq!(reached.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(reached.distance(&'e'), None);
+        assert!(dijkstra('a', edges, |n: &char| *n == 'e').is_none());
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = maze();
+        let goal = Point::new(5, 3);
+        let successors = |p: &Point| {
+            open_neighbours(&maze, p)
+                .into_iter()
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let path = astar(
+            Point::new(0, 0),
+            successors,
+            |p: &Point| goal.dist(p),
+            |p: &Point| *p == goal,
+        )
+        .unwrap();
+        let expected = dijkstra(Point::new(0, 0), successors, |p: &Point| *p == goal).unwrap();
+        assert_eq!(path.distance, expected.distance);
+        assert!(path.distances.len() <= expected.distances.len());
+    }
+}
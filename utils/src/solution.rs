@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// Uniform interface for a single day's puzzle.
+///
+/// The raw puzzle text is turned into `Input` once by `parse`, which reports
+/// malformed input as a `ParseError`, and both parts are then computed from
+/// that parsed input. Days that need extra knobs (like the row to inspect on
+/// day 15) take them through `Params`, whose `Default` holds the values for
+/// the real puzzle input.
+pub trait Solution {
+    type Input;
+    type Params: Default;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer1;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer2;
+
+    /// How the answer to `part` comes about, such as the moves behind it,
+    /// for days that can tell. `aoc run --explain` prints it.
+    fn explain(_input: &Self::Input, _params: &Self::Params, _part: u32) -> Option<String> {
+        None
+    }
+}
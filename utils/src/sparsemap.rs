@@ -12,6 +12,15 @@ pub struct SparseMap<T> {
     map: HashMap<Point, T>,
 }
 
+impl<T> Default for SparseMap<T>
+where
+    T: Debug + Default + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> SparseMap<T>
 where
     T: Debug + Default + Copy,
@@ -68,7 +77,7 @@ where
                     write!(f, ".")?;
                 }
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }